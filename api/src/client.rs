@@ -1,21 +1,38 @@
+use std::time::Duration;
+
 use clusterizer_common::{
     errors::{
-        CreateFileError, FetchTasksError, RegisterError, SubmitResultError, ValidateFetchError,
+        AssimilateFetchError, AssimilateSubmitError, CreateApiKeyError, CreateFileError,
+        CreateTeamError, CreateWebhookError, FetchTasksError, Infallible, JoinTeamError,
+        KickTeamMemberError, LeaveTeamError, NotFound, RegisterError, RemoveProjectMemberError,
+        RevokeApiKeyError, SetProjectMemberError, SetTaskPriorityError, SetUserAdminError,
+        SetUserDisabledError, SubmitResultError, UserActivityError, ValidateFetchError,
         ValidateSubmitError,
     },
-    records::{File, Get, Project, Task},
+    records::{ApiKey, File, Get, Host, Project, Task, Team, User, UserQuota},
     requests::{
-        CreateFileRequest, FetchTasksRequest, RegisterRequest, SubmitResultRequest,
-        ValidateSubmitRequest,
+        AssimilateSubmitRequest, CreateApiKeyRequest, CreateFileRequest, CreateTeamRequest,
+        CreateWebhookRequest, EventsRequest, FetchTasksRequest, KickTeamMemberRequest,
+        RegisterRequest, RemoveProjectMemberRequest, SetBetaOptInRequest, SetProjectMemberRequest,
+        SetTaskPriorityRequest, SetUserAdminRequest, SetUserDisabledRequest, SubmitResultRequest,
+        TeamStatsRequest, UserStatsRequest, ValidateSubmitRequest,
+    },
+    responses::{
+        AssimilateFetchResponse, CreateApiKeyResponse, CreateWebhookResponse, FetchTasksResponse,
+        GlobalStatsResponse, ProjectStatsResponse, RegisterResponse, TeamStatsResponse,
+        UserActivityResponse, UserStatsResponse,
     },
-    responses::RegisterResponse,
-    types::Id,
+    types::{Event, Id},
 };
-use reqwest::{IntoUrl, RequestBuilder, Response, header};
+use futures_util::{Stream, StreamExt, stream};
+use reqwest::{IntoUrl, RequestBuilder, Response, StatusCode, Url, header};
 use serde::{Serialize, de::DeserializeOwned};
+use tokio::time;
 
 use crate::result::{ApiError, ApiResult};
 
+const MAX_ATTEMPTS: u32 = 5;
+
 pub struct ApiClient {
     client: reqwest::Client,
     url: String,
@@ -44,10 +61,39 @@ impl ApiClient {
         Ok(self.send_post(url, request).await?.json().await?)
     }
 
+    pub async fn hosts(&self) -> ApiResult<Vec<Host>, Infallible> {
+        let url = format!("{}/users/me/hosts", self.url);
+        Ok(self.send_get(url).await?.json().await?)
+    }
+
+    pub async fn user_quotas(&self) -> ApiResult<Vec<UserQuota>, Infallible> {
+        let url = format!("{}/users/me/quotas", self.url);
+        Ok(self.send_get(url).await?.json().await?)
+    }
+
+    pub async fn api_keys(&self) -> ApiResult<Vec<ApiKey>, Infallible> {
+        let url = format!("{}/users/me/api_keys", self.url);
+        Ok(self.send_get(url).await?.json().await?)
+    }
+
+    pub async fn create_api_key(
+        &self,
+        request: &CreateApiKeyRequest,
+    ) -> ApiResult<CreateApiKeyResponse, CreateApiKeyError> {
+        let url = format!("{}/users/me/api_keys", self.url);
+        Ok(self.send_post(url, request).await?.json().await?)
+    }
+
+    pub async fn revoke_api_key(&self, api_key_id: Id<ApiKey>) -> ApiResult<(), RevokeApiKeyError> {
+        let url = format!("{}/users/me/api_keys/{api_key_id}/revoke", self.url);
+        self.send(self.client.post(url)).await?;
+        Ok(())
+    }
+
     pub async fn fetch_tasks(
         &self,
         request: &FetchTasksRequest,
-    ) -> ApiResult<Vec<Task>, FetchTasksError> {
+    ) -> ApiResult<FetchTasksResponse, FetchTasksError> {
         let url = format!("{}/fetch_tasks", self.url);
         Ok(self.send_post(url, request).await?.json().await?)
     }
@@ -87,6 +133,205 @@ impl ApiClient {
         Ok(self.send_post(url, request).await?.json().await?)
     }
 
+    pub async fn create_webhook(
+        &self,
+        request: &CreateWebhookRequest,
+    ) -> ApiResult<CreateWebhookResponse, CreateWebhookError> {
+        let url = format!("{}/webhooks", self.url);
+        Ok(self.send_post(url, request).await?.json().await?)
+    }
+
+    pub async fn set_beta_opt_in(
+        &self,
+        request: &SetBetaOptInRequest,
+    ) -> ApiResult<(), Infallible> {
+        let url = format!("{}/set_beta_opt_in", self.url);
+        self.send_post(url, request).await?;
+        Ok(())
+    }
+
+    pub async fn set_user_admin(
+        &self,
+        user_id: Id<User>,
+        request: &SetUserAdminRequest,
+    ) -> ApiResult<(), SetUserAdminError> {
+        let url = format!("{}/admin/users/{user_id}/admin", self.url);
+        self.send_post(url, request).await?;
+        Ok(())
+    }
+
+    pub async fn set_user_disabled(
+        &self,
+        user_id: Id<User>,
+        request: &SetUserDisabledRequest,
+    ) -> ApiResult<(), SetUserDisabledError> {
+        let url = format!("{}/admin/users/{user_id}/disabled", self.url);
+        self.send_post(url, request).await?;
+        Ok(())
+    }
+
+    pub async fn user_activity(
+        &self,
+        user_id: Id<User>,
+    ) -> ApiResult<UserActivityResponse, UserActivityError> {
+        let url = format!("{}/admin/users/{user_id}/activity", self.url);
+        Ok(self.send_get(url).await?.json().await?)
+    }
+
+    pub async fn set_task_priority(
+        &self,
+        project_id: Id<Project>,
+        request: &SetTaskPriorityRequest,
+    ) -> ApiResult<u64, SetTaskPriorityError> {
+        let url = format!("{}/set_task_priority/{project_id}", self.url);
+        Ok(self.send_post(url, request).await?.json().await?)
+    }
+
+    pub async fn set_project_member(
+        &self,
+        project_id: Id<Project>,
+        request: &SetProjectMemberRequest,
+    ) -> ApiResult<(), SetProjectMemberError> {
+        let url = format!("{}/projects/{project_id}/members", self.url);
+        self.send_post(url, request).await?;
+        Ok(())
+    }
+
+    pub async fn remove_project_member(
+        &self,
+        project_id: Id<Project>,
+        request: &RemoveProjectMemberRequest,
+    ) -> ApiResult<(), RemoveProjectMemberError> {
+        let url = format!("{}/projects/{project_id}/members/remove", self.url);
+        self.send_post(url, request).await?;
+        Ok(())
+    }
+
+    pub async fn assimilate_fetch(
+        &self,
+        project_id: Id<Project>,
+    ) -> ApiResult<AssimilateFetchResponse, AssimilateFetchError> {
+        let url = format!("{}/assimilate/{project_id}", self.url);
+        Ok(self.send_get(url).await?.json().await?)
+    }
+
+    pub async fn assimilate_submit(
+        &self,
+        project_id: Id<Project>,
+        request: &AssimilateSubmitRequest,
+    ) -> ApiResult<(), AssimilateSubmitError> {
+        let url = format!("{}/assimilate/{project_id}", self.url);
+        self.send_post(url, request).await?;
+        Ok(())
+    }
+
+    pub async fn user_stats(
+        &self,
+        request: &UserStatsRequest,
+    ) -> ApiResult<Vec<UserStatsResponse>, Infallible> {
+        let mut url = Url::parse(&format!("{}/stats/users", self.url)).unwrap();
+        url.set_query(Some(&serde_qs::to_string(request).unwrap()));
+
+        Ok(self.send_get(url).await?.json().await?)
+    }
+
+    pub async fn project_stats(
+        &self,
+        project_id: Id<Project>,
+    ) -> ApiResult<ProjectStatsResponse, NotFound> {
+        let url = format!("{}/stats/projects/{project_id}", self.url);
+        Ok(self.send_get(url).await?.json().await?)
+    }
+
+    pub async fn global_stats(&self) -> ApiResult<GlobalStatsResponse, Infallible> {
+        let url = format!("{}/stats/global", self.url);
+        Ok(self.send_get(url).await?.json().await?)
+    }
+
+    pub async fn team_stats(
+        &self,
+        request: &TeamStatsRequest,
+    ) -> ApiResult<Vec<TeamStatsResponse>, Infallible> {
+        let mut url = Url::parse(&format!("{}/stats/teams", self.url)).unwrap();
+        url.set_query(Some(&serde_qs::to_string(request).unwrap()));
+
+        Ok(self.send_get(url).await?.json().await?)
+    }
+
+    pub async fn create_team(
+        &self,
+        request: &CreateTeamRequest,
+    ) -> ApiResult<Id<Team>, CreateTeamError> {
+        let url = format!("{}/teams", self.url);
+        Ok(self.send_post(url, request).await?.json().await?)
+    }
+
+    pub async fn join_team(&self, team_id: Id<Team>) -> ApiResult<(), JoinTeamError> {
+        let url = format!("{}/teams/{team_id}/join", self.url);
+        self.send(self.client.post(url)).await?;
+        Ok(())
+    }
+
+    pub async fn leave_team(&self) -> ApiResult<(), LeaveTeamError> {
+        let url = format!("{}/teams/leave", self.url);
+        self.send(self.client.post(url)).await?;
+        Ok(())
+    }
+
+    pub async fn kick_team_member(
+        &self,
+        team_id: Id<Team>,
+        request: &KickTeamMemberRequest,
+    ) -> ApiResult<(), KickTeamMemberError> {
+        let url = format!("{}/teams/{team_id}/kick", self.url);
+        self.send_post(url, request).await?;
+        Ok(())
+    }
+
+    /// Streams changes to tasks, assignments and results as they happen.
+    pub async fn events(
+        &self,
+        request: &EventsRequest,
+    ) -> ApiResult<impl Stream<Item = ApiResult<Event, Infallible>> + use<>, Infallible> {
+        let mut url = Url::parse(&format!("{}/events", self.url)).unwrap();
+        url.set_query(Some(&serde_qs::to_string(request).unwrap()));
+
+        let bytes = Box::pin(self.send_get(url).await?.bytes_stream());
+
+        Ok(stream::unfold(
+            (bytes, Vec::new()),
+            |(mut bytes, mut buffer)| async move {
+                loop {
+                    // Each event is terminated by an empty line.
+                    if let Some(end) = buffer.windows(2).position(|window| window == b"\n\n") {
+                        let lines: Vec<_> = buffer.drain(..end + 2).collect();
+                        let data: Vec<_> = String::from_utf8_lossy(&lines)
+                            .lines()
+                            .filter_map(|line| line.strip_prefix("data:"))
+                            .map(|data| data.strip_prefix(' ').unwrap_or(data).to_string())
+                            .collect();
+
+                        // Keep-alive comments carry no data.
+                        if data.is_empty() {
+                            continue;
+                        }
+
+                        let event = serde_json::from_str(&data.join("\n"))
+                            .map_err(|err| ApiError::String(err.to_string()));
+
+                        return Some((event, (bytes, buffer)));
+                    }
+
+                    match bytes.next().await {
+                        Some(Ok(chunk)) => buffer.extend_from_slice(&chunk),
+                        Some(Err(err)) => return Some((Err(err.into()), (bytes, buffer))),
+                        None => return None,
+                    }
+                }
+            },
+        ))
+    }
+
     async fn send_get<Error: DeserializeOwned>(
         &self,
         url: impl IntoUrl,
@@ -110,7 +355,33 @@ impl ApiClient {
             request = request.bearer_auth(api_key);
         }
 
-        let response = request.send().await?;
+        let mut attempts = 1;
+
+        let response = loop {
+            let retry = request.try_clone();
+            let response = request.send().await?;
+
+            // Wait as long as the server asks when rate limited, unless the request cannot be
+            // sent again.
+            let retry_after = response
+                .headers()
+                .get(header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse().ok())
+                .map(Duration::from_secs);
+
+            match (response.status(), retry_after, retry) {
+                (StatusCode::TOO_MANY_REQUESTS, Some(retry_after), Some(retry))
+                    if attempts < MAX_ATTEMPTS =>
+                {
+                    time::sleep(retry_after).await;
+
+                    attempts += 1;
+                    request = retry;
+                }
+                _ => break response,
+            }
+        };
 
         if let Some(err) = response.error_for_status_ref().err() {
             if response
@@ -118,7 +389,21 @@ impl ApiClient {
                 .get(header::CONTENT_TYPE)
                 .is_some_and(|value| value == "application/json")
             {
-                Err(ApiError::Specific(response.json().await?))
+                let status = response.status();
+                let bytes = response.bytes().await?;
+
+                // Route errors can be sent with 403 too, so only take the body as an
+                // authentication error if it is one.
+                if (status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN)
+                    && let Ok(err) = serde_json::from_slice(&bytes)
+                {
+                    return Err(ApiError::Unauthorized(err));
+                }
+
+                Err(ApiError::Specific(
+                    serde_json::from_slice(&bytes)
+                        .map_err(|err| ApiError::String(err.to_string()))?,
+                ))
             } else {
                 let string = response.text().await?;
 
@@ -1,5 +1,9 @@
+use clusterizer_common::errors::AuthError;
+
 pub enum ApiError<E> {
     Specific(E),
+    // The server rejected the API key, or the user it belongs to.
+    Unauthorized(AuthError),
     String(String),
     Reqwest(reqwest::Error),
 }
@@ -0,0 +1,84 @@
+use clusterizer_api::client::ApiClient;
+use clusterizer_client::result::ClientResult;
+use clusterizer_common::requests::{SetUserAdminRequest, SetUserDisabledRequest};
+use tracing::{debug, info};
+
+use crate::args::{AdminArgs, AdminCommands};
+
+pub async fn admin(client: ApiClient, args: AdminArgs) -> ClientResult<()> {
+    match args.command {
+        AdminCommands::SetAdmin(args) => {
+            debug!("Setting admin...");
+
+            client
+                .set_user_admin(
+                    args.user_id,
+                    &SetUserAdminRequest {
+                        is_admin: args.enabled,
+                    },
+                )
+                .await?;
+
+            info!(
+                "Successfully set admin of user {} to {}.",
+                args.user_id, args.enabled
+            );
+        }
+        AdminCommands::SetDisabled(args) => {
+            debug!("Setting disabled...");
+
+            client
+                .set_user_disabled(
+                    args.user_id,
+                    &SetUserDisabledRequest {
+                        disabled: args.disabled,
+                    },
+                )
+                .await?;
+
+            info!(
+                "Successfully set disabled of user {} to {}.",
+                args.user_id, args.disabled
+            );
+        }
+        AdminCommands::Activity(args) => {
+            let activity = client.user_activity(args.user_id).await?;
+
+            println!("Assignments:");
+            println!("{:>10}  {:>10}  {:<32}  State", "ID", "Task", "Created");
+
+            for assignment in activity.assignments {
+                println!(
+                    "{:>10}  {:>10}  {:<32}  {:?}",
+                    assignment.id.to_string(),
+                    assignment.task_id.to_string(),
+                    assignment.created_at.to_rfc3339(),
+                    assignment.state
+                );
+            }
+
+            println!();
+            println!("Results:");
+            println!(
+                "{:>10}  {:>10}  {:<32}  {:>9}  State",
+                "ID", "Assignment", "Created", "Exit code"
+            );
+
+            for result in activity.results {
+                println!(
+                    "{:>10}  {:>10}  {:<32}  {:>9}  {:?}",
+                    result.id.to_string(),
+                    result.assignment_id.to_string(),
+                    result.created_at.to_rfc3339(),
+                    result
+                        .exit_code
+                        .map(|exit_code| exit_code.to_string())
+                        .unwrap_or_default(),
+                    result.state
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use clusterizer_api::client::ApiClient;
+use clusterizer_client::result::ClientResult;
+use clusterizer_common::requests::CreateApiKeyRequest;
+use tracing::{debug, info};
+
+use crate::args::{ApiKeyArgs, ApiKeyCommands};
+
+pub async fn api_key(client: ApiClient, args: ApiKeyArgs) -> ClientResult<()> {
+    match args.command {
+        ApiKeyCommands::List => {
+            println!("{:>8}  {:<32}  {:<32}  Scopes", "ID", "Created", "Revoked");
+
+            for api_key in client.api_keys().await? {
+                println!(
+                    "{:>8}  {:<32}  {:<32}  {}",
+                    api_key.id.to_string(),
+                    api_key.created_at.to_rfc3339(),
+                    api_key
+                        .revoked_at
+                        .map(|revoked_at| revoked_at.to_rfc3339())
+                        .unwrap_or_default(),
+                    api_key
+                        .scopes
+                        .map(|scopes| scopes
+                            .iter()
+                            .map(|scope| scope.to_string())
+                            .collect::<Vec<_>>()
+                            .join(" "))
+                        .unwrap_or_else(|| "*".to_string())
+                );
+            }
+        }
+        ApiKeyCommands::Create(args) => {
+            debug!("Creating API key...");
+
+            let response = client
+                .create_api_key(&CreateApiKeyRequest {
+                    revoke_others: args.revoke_others,
+                    scopes: args.scopes,
+                })
+                .await?;
+
+            println!("{}", response.api_key);
+            info!(
+                "Successfully created API key with ID: {}",
+                response.api_key_id
+            );
+        }
+        ApiKeyCommands::Revoke(args) => {
+            debug!("Revoking API key...");
+
+            client.revoke_api_key(args.api_key_id).await?;
+
+            info!("Successfully revoked API key {}.", args.api_key_id);
+        }
+    }
+
+    Ok(())
+}
@@ -1,9 +1,13 @@
 use std::{num::NonZero, path::PathBuf, thread};
 
 use clap::{
-    Args, Parser, Subcommand,
+    ArgAction, Args, Parser, Subcommand,
     builder::{OsStr, Resettable},
 };
+use clusterizer_common::{
+    records::{ApiKey, Project, Team, User},
+    types::{Id, ProjectRole, Scope},
+};
 
 #[derive(Debug, Parser)]
 #[command(name = "Clusterizer RS")]
@@ -26,6 +30,20 @@ pub enum Commands {
     Run(RunArgs),
     /// Create a new file on the server
     CreateFile(CreateFileArgs),
+    /// Opt in or out of receiving beta project versions
+    SetBetaOptIn(SetBetaOptInArgs),
+    /// Pipe the canonical results of a project into a program
+    Assimilate(AssimilateArgs),
+    /// Show statistics of users, a project or the whole server
+    Stats(StatsArgs),
+    /// Create, join or leave a team
+    Team(TeamArgs),
+    /// List, rotate or revoke your API keys
+    ApiKey(ApiKeyArgs),
+    /// Manage the members of a project
+    Member(MemberArgs),
+    /// Manage users as an admin
+    Admin(AdminArgs),
 }
 
 #[derive(Debug, Args)]
@@ -52,6 +70,10 @@ impl RunArgs {
     pub fn temp_dir(&self) -> PathBuf {
         self.cache_dir.join("tmp")
     }
+
+    pub fn host_name_file(&self) -> PathBuf {
+        self.cache_dir.join("host_name")
+    }
 }
 
 #[derive(Debug, Args)]
@@ -60,6 +82,211 @@ pub struct CreateFileArgs {
     pub url: String,
 }
 
+#[derive(Debug, Args)]
+pub struct SetBetaOptInArgs {
+    #[arg(long, short, action = ArgAction::Set)]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct AssimilateArgs {
+    #[arg(long, short)]
+    pub project_id: Id<Project>,
+    /// Program that receives each canonical stdout on its stdin, with the task in the
+    /// CLUSTERIZER_TASK_ID and CLUSTERIZER_TASK_STDIN environment variables
+    #[arg(long)]
+    pub program: PathBuf,
+}
+
+#[derive(Debug, Args)]
+pub struct StatsArgs {
+    #[command(subcommand)]
+    pub command: StatsCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum StatsCommands {
+    /// Show the user leaderboard
+    Users(UserStatsArgs),
+    /// Show statistics of a project
+    Project(ProjectStatsArgs),
+    /// Show statistics of the whole server
+    Global,
+    /// Show the team leaderboard
+    Teams(TeamStatsArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct UserStatsArgs {
+    #[arg(long, short, default_value_t = 0)]
+    pub offset: i64,
+    #[arg(long, short, default_value_t = 20)]
+    pub limit: i64,
+}
+
+#[derive(Debug, Args)]
+pub struct TeamStatsArgs {
+    #[arg(long, short, default_value_t = 0)]
+    pub offset: i64,
+    #[arg(long, short, default_value_t = 20)]
+    pub limit: i64,
+}
+
+#[derive(Debug, Args)]
+pub struct ProjectStatsArgs {
+    #[arg(long, short)]
+    pub project_id: Id<Project>,
+}
+
+#[derive(Debug, Args)]
+pub struct TeamArgs {
+    #[command(subcommand)]
+    pub command: TeamCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TeamCommands {
+    /// Create a new team and join it as its founder
+    Create(CreateTeamArgs),
+    /// Join a team, leaving the current one
+    Join(JoinTeamArgs),
+    /// Leave the current team
+    Leave,
+    /// Remove a member from a team you founded
+    Kick(KickTeamMemberArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct CreateTeamArgs {
+    #[arg(long, short)]
+    pub name: String,
+}
+
+#[derive(Debug, Args)]
+pub struct JoinTeamArgs {
+    #[arg(long, short)]
+    pub team_id: Id<Team>,
+}
+
+#[derive(Debug, Args)]
+pub struct KickTeamMemberArgs {
+    #[arg(long, short)]
+    pub team_id: Id<Team>,
+    #[arg(long, short)]
+    pub user_id: Id<User>,
+}
+
+#[derive(Debug, Args)]
+pub struct ApiKeyArgs {
+    #[command(subcommand)]
+    pub command: ApiKeyCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ApiKeyCommands {
+    /// List all your API keys
+    List,
+    /// Create a new API key
+    Create(CreateApiKeyArgs),
+    /// Revoke an API key
+    Revoke(RevokeApiKeyArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct CreateApiKeyArgs {
+    /// Revoke all other keys, including the one in use
+    #[arg(long)]
+    pub revoke_others: bool,
+    /// Restrict the key to a scope, like tasks:fetch or project:1:validate
+    #[arg(long = "scope")]
+    pub scopes: Option<Vec<Scope>>,
+}
+
+#[derive(Debug, Args)]
+pub struct RevokeApiKeyArgs {
+    #[arg(long, short)]
+    pub api_key_id: Id<ApiKey>,
+}
+
+#[derive(Debug, Args)]
+pub struct MemberArgs {
+    #[command(subcommand)]
+    pub command: MemberCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MemberCommands {
+    /// List the members of a project
+    List(ListMembersArgs),
+    /// Add a member to a project or change their role
+    Set(SetMemberArgs),
+    /// Remove a member from a project
+    Remove(RemoveMemberArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct ListMembersArgs {
+    #[arg(long, short)]
+    pub project_id: Id<Project>,
+}
+
+#[derive(Debug, Args)]
+pub struct SetMemberArgs {
+    #[arg(long, short)]
+    pub project_id: Id<Project>,
+    #[arg(long, short)]
+    pub user_id: Id<User>,
+    /// One of viewer, validator, maintainer or owner
+    #[arg(long, short, value_parser = project_role)]
+    pub role: ProjectRole,
+}
+
+#[derive(Debug, Args)]
+pub struct RemoveMemberArgs {
+    #[arg(long, short)]
+    pub project_id: Id<Project>,
+    #[arg(long, short)]
+    pub user_id: Id<User>,
+}
+
+#[derive(Debug, Args)]
+pub struct AdminArgs {
+    #[command(subcommand)]
+    pub command: AdminCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AdminCommands {
+    /// Grant or take away admin rights
+    SetAdmin(SetAdminArgs),
+    /// Disable or enable a user
+    SetDisabled(SetDisabledArgs),
+    /// Show the recent assignments and results of a user
+    Activity(ActivityArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct SetAdminArgs {
+    #[arg(long, short)]
+    pub user_id: Id<User>,
+    #[arg(long, short, action = ArgAction::Set)]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct SetDisabledArgs {
+    #[arg(long, short)]
+    pub user_id: Id<User>,
+    #[arg(long, short, action = ArgAction::Set)]
+    pub disabled: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ActivityArgs {
+    #[arg(long, short)]
+    pub user_id: Id<User>,
+}
+
 fn cache_dir() -> Resettable<OsStr> {
     dirs::cache_dir()
         .map(|path| path.join("clusterizer").into_os_string().into())
@@ -69,3 +296,13 @@ fn cache_dir() -> Resettable<OsStr> {
 fn threads() -> usize {
     thread::available_parallelism().map_or(1, NonZero::get)
 }
+
+fn project_role(src: &str) -> Result<ProjectRole, String> {
+    match src {
+        "viewer" => Ok(ProjectRole::Viewer),
+        "validator" => Ok(ProjectRole::Validator),
+        "maintainer" => Ok(ProjectRole::Maintainer),
+        "owner" => Ok(ProjectRole::Owner),
+        _ => Err(format!("invalid role: {src}")),
+    }
+}
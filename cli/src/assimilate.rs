@@ -0,0 +1,96 @@
+use std::{collections::HashMap, io::ErrorKind, process::Stdio, time::Duration};
+
+use clusterizer_api::client::ApiClient;
+use clusterizer_client::result::{ClientError, ClientResult};
+use clusterizer_common::{
+    records::{Result, Task},
+    requests::AssimilateSubmitRequest,
+};
+use tokio::{io::AsyncWriteExt, process::Command, time};
+use tracing::{debug, info};
+
+use crate::args::AssimilateArgs;
+
+pub async fn assimilate(client: ApiClient, args: AssimilateArgs) -> ClientResult<()> {
+    loop {
+        let response = client.assimilate_fetch(args.project_id).await?;
+
+        if response.tasks.is_empty() {
+            info!("No tasks to assimilate. Sleeping before attempting again.");
+            time::sleep(Duration::from_secs(15)).await;
+            continue;
+        }
+
+        let canonical_results_by_id: HashMap<_, _> = response
+            .canonical_results
+            .into_iter()
+            .map(|result| (result.id, result))
+            .collect();
+
+        let mut task_ids = Vec::new();
+        let mut failure = None;
+
+        for task in response.tasks {
+            let Some(result) = task
+                .canonical_result_id
+                .and_then(|id| canonical_results_by_id.get(&id))
+            else {
+                continue;
+            };
+
+            debug!("Assimilating task id: {}", task.id);
+
+            if let Err(err) = assimilate_task(&args, &task, result).await {
+                failure = Some(err);
+                break;
+            }
+
+            task_ids.push(task.id);
+        }
+
+        // Mark the tasks that were assimilated before a failure, including one to spawn or feed
+        // the assimilator, so they are not repeated.
+        if !task_ids.is_empty() {
+            info!("Assimilated {} tasks.", task_ids.len());
+
+            client
+                .assimilate_submit(args.project_id, &AssimilateSubmitRequest { task_ids })
+                .await?;
+        }
+
+        if let Some(failure) = failure {
+            Err(failure)?;
+        }
+    }
+}
+
+/// Runs the assimilator with the canonical stdout of the task as its stdin.
+async fn assimilate_task(args: &AssimilateArgs, task: &Task, result: &Result) -> ClientResult<()> {
+    let mut child = Command::new(&args.program)
+        .env("CLUSTERIZER_TASK_ID", task.id.to_string())
+        .env("CLUSTERIZER_TASK_STDIN", &task.stdin)
+        .stdin(Stdio::piped())
+        .spawn()?;
+
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let stdout = result.stdout.clone();
+
+    // Written concurrently, so a large result cannot block on a full pipe while the program
+    // waits for its own output to be read.
+    let write = tokio::spawn(async move { stdin.write_all(stdout.as_bytes()).await });
+    let status = child.wait().await?;
+
+    // Assimilators that do not need the canonical output may exit without reading it.
+    match write.await? {
+        Err(err) if err.kind() != ErrorKind::BrokenPipe => Err(err)?,
+        _ => {}
+    }
+
+    if !status.success() {
+        Err(ClientError::Specific(
+            format!("assimilator exited with {status} for task {}", task.id).into(),
+        ))?;
+    }
+
+    Ok(())
+}
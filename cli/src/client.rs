@@ -3,36 +3,58 @@ use std::{
     env,
     ffi::OsString,
     fs,
+    hash::{BuildHasher, Hasher, RandomState},
     io::{Cursor, ErrorKind},
     iter::{self, Empty},
     path::PathBuf,
-    process::{Output, Stdio},
-    sync::Arc,
-    time::Duration,
+    process::Stdio,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
 use clusterizer_api::{client::ApiClient, result::ApiError};
 use clusterizer_client::result::ClientResult;
 use clusterizer_common::{
-    errors::SubmitResultError,
-    records::{
-        File, FileFilter, Platform, PlatformFilter, Project, ProjectFilter, ProjectVersion,
-        ProjectVersionFilter, Task,
-    },
+    errors::{FetchTasksError, SubmitResultError},
+    records::{File, Platform, PlatformFilter, Project, ProjectFilter, ProjectVersion, Task},
     requests::{FetchTasksRequest, SubmitResultRequest},
-    types::Id,
+    responses::FetchTasksMetadata,
+    types::{HostInfo, Id, Interval},
 };
 use clusterizer_util::Hex;
-use tokio::{io::AsyncWriteExt, process::Command, task::JoinSet, time};
+use sysinfo::{CpuRefreshKind, MemoryRefreshKind, RefreshKind, System};
+use tokio::{process::Command, task::JoinSet, time};
 use tracing::{debug, info, warn};
 use zip::ZipArchive;
 
-use crate::args::RunArgs;
+use crate::{
+    args::RunArgs,
+    process::{self, MeasuredOutput},
+};
+
+/// How often the enabled projects are listed again, so projects created while running are picked
+/// up.
+const PROJECT_REFRESH_INTERVAL: Duration = Duration::from_secs(600);
 
 struct ClusterizerClient {
     client: ApiClient,
     args: RunArgs,
-    platform_ids: Vec<Id<Platform>>,
+    host: HostInfo,
+    project_ids: Mutex<ProjectIds>,
+    metadata: Mutex<Metadata>,
+}
+
+struct ProjectIds {
+    project_ids: Vec<Id<Project>>,
+    refreshed_at: Instant,
+}
+
+#[derive(Default)]
+struct Metadata {
+    etag: Option<String>,
+    projects_by_project_id: HashMap<Id<Project>, Project>,
+    project_versions_by_project_version_id: HashMap<Id<ProjectVersion>, ProjectVersion>,
+    files_by_file_id: HashMap<Id<File>, File>,
 }
 
 struct TaskInfo {
@@ -42,9 +64,49 @@ struct TaskInfo {
     file: File,
 }
 
+impl Metadata {
+    /// The server always sends the metadata of every project the host can run, so anything not in
+    /// `metadata` belongs to projects that are no longer returned and is dropped.
+    fn replace(&mut self, metadata: FetchTasksMetadata) {
+        self.projects_by_project_id = metadata
+            .projects
+            .into_iter()
+            .map(|project| (project.id, project))
+            .collect();
+
+        self.project_versions_by_project_version_id = metadata
+            .project_versions
+            .into_iter()
+            .map(|project_version| (project_version.id, project_version))
+            .collect();
+
+        self.files_by_file_id = metadata
+            .files
+            .into_iter()
+            .map(|file| (file.id, file))
+            .collect();
+    }
+
+    fn task_info(&self, task: Task, project_version_id: Id<ProjectVersion>) -> Option<TaskInfo> {
+        let project = self.projects_by_project_id.get(&task.project_id)?;
+        let project_version = self
+            .project_versions_by_project_version_id
+            .get(&project_version_id)
+            .filter(|project_version| project_version.project_id == task.project_id)?;
+        let file = self.files_by_file_id.get(&project_version.file_id)?;
+
+        Some(TaskInfo {
+            task,
+            project: project.clone(),
+            project_version: project_version.clone(),
+            file: file.clone(),
+        })
+    }
+}
+
 enum Return {
     FetchTasks(Vec<TaskInfo>),
-    ExecuteTask(Id<Task>, Output),
+    ExecuteTask(Id<Task>, MeasuredOutput),
     SubmitResult,
 }
 
@@ -92,65 +154,77 @@ impl ClusterizerClient {
         Ok(())
     }
 
+    async fn project_ids(&self) -> ClientResult<Vec<Id<Project>>> {
+        {
+            let project_ids = self.project_ids.lock().unwrap();
+
+            if project_ids.refreshed_at.elapsed() < PROJECT_REFRESH_INTERVAL {
+                return Ok(project_ids.project_ids.clone());
+            }
+        }
+
+        let project_ids = enabled_project_ids(&self.client).await?;
+
+        *self.project_ids.lock().unwrap() = ProjectIds {
+            project_ids: project_ids.clone(),
+            refreshed_at: Instant::now(),
+        };
+
+        Ok(project_ids)
+    }
+
     async fn fetch_tasks(self: Arc<Self>) -> ClientResult<Return> {
         let tasks = loop {
-            let project_versions_by_project_id: HashMap<_, _> = self
-                .client
-                .get(&ProjectVersionFilter::default().disabled_at(vec![None]))
-                .await?
-                .into_iter()
-                .filter(|project_version| self.platform_ids.contains(&project_version.platform_id))
-                .map(|project_version| (project_version.project_id, project_version))
-                .collect();
-
-            let projects_by_project_id: HashMap<_, _> = self
-                .client
-                .get(&ProjectFilter::default().disabled_at(vec![None]))
-                .await?
-                .into_iter()
-                .filter(|project| project_versions_by_project_id.contains_key(&project.id))
-                .map(|project| (project.id, project))
-                .collect();
-
-            let files_by_file_id: HashMap<_, _> = self
-                .client
-                .get(&FileFilter::default())
-                .await?
-                .into_iter()
-                .map(|file| (file.id, file))
-                .collect();
-
-            let get_task_info = |task: &Task| {
-                let project = projects_by_project_id.get(&task.project_id)?;
-                let project_version = project_versions_by_project_id.get(&task.project_id)?;
-                let file = files_by_file_id.get(&project_version.file_id)?;
-
-                Some(TaskInfo {
-                    task: task.clone(),
-                    file: file.clone(),
-                    project: project.clone(),
-                    project_version: project_version.clone(),
-                })
-            };
+            let project_ids = self.project_ids().await?;
+            let metadata_etag = self.metadata.lock().unwrap().etag.clone();
 
-            let tasks: Vec<_> = self
+            let response = match self
                 .client
                 .fetch_tasks(&FetchTasksRequest {
-                    project_ids: projects_by_project_id.keys().copied().collect(),
+                    project_ids,
                     limit: self.args.threads,
+                    host: self.host.clone(),
+                    metadata_etag,
                 })
-                .await?
-                .into_iter()
-                .filter_map(|task| {
-                    let info = get_task_info(&task);
+                .await
+            {
+                Err(ApiError::Specific(
+                    err @ (FetchTasksError::InProgressLimitReached
+                    | FetchTasksError::DailyQuotaReached
+                    | FetchTasksError::ProjectInProgressLimitReached),
+                )) => {
+                    info!("{err}. Sleeping before attempting again.");
+                    time::sleep(Duration::from_secs(15)).await;
+
+                    continue;
+                }
+                result => result?,
+            };
 
-                    if info.is_none() {
-                        warn!("Unwanted task received from server.");
-                    }
+            let tasks: Vec<_> = {
+                let mut metadata = self.metadata.lock().unwrap();
 
-                    info
-                })
-                .collect();
+                if let Some(new_metadata) = response.metadata {
+                    metadata.replace(new_metadata);
+                }
+
+                metadata.etag = Some(response.metadata_etag);
+
+                response
+                    .tasks
+                    .into_iter()
+                    .zip(response.project_version_ids)
+                    .filter_map(|(task, project_version_id)| {
+                        let info = metadata.task_info(task, project_version_id);
+
+                        if info.is_none() {
+                            warn!("Unwanted task received from server.");
+                        }
+
+                        info
+                    })
+                    .collect()
+            };
 
             if !tasks.is_empty() {
                 break tasks;
@@ -183,7 +257,16 @@ impl ClusterizerClient {
             "Project id: {}, Project name: {}",
             task.project_id, project.name
         );
-        debug!("Platform id: {}", project_version.platform_id);
+        debug!(
+            "Platform id: {}, Project version: {}{}",
+            project_version.platform_id,
+            project_version.version,
+            if project_version.is_beta {
+                " (beta)"
+            } else {
+                ""
+            }
+        );
         debug!("Slot dir: {}", slot_dir.path().display());
 
         let program = self
@@ -195,21 +278,10 @@ impl ClusterizerClient {
 
         let args: Empty<OsString> = iter::empty();
 
-        let mut child = Command::new(program)
-            .args(args)
-            .current_dir(&slot_dir)
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
-
-        let mut stdin = child.stdin.take().unwrap();
+        let mut command = std::process::Command::new(program);
+        command.args(args).current_dir(&slot_dir);
 
-        tokio::spawn(async move {
-            stdin.write_all(task.stdin.as_bytes()).await.unwrap();
-        });
-
-        let output = child.wait_with_output().await?;
+        let output = process::output(command, task.stdin).await?;
 
         Ok(Return::ExecuteTask(task.id, output))
     }
@@ -217,12 +289,18 @@ impl ClusterizerClient {
     async fn submit_result(
         self: Arc<Self>,
         task_id: Id<Task>,
-        output: Output,
+        MeasuredOutput {
+            output,
+            cpu_time,
+            wall_time,
+        }: MeasuredOutput,
     ) -> ClientResult<Return> {
         let request = SubmitResultRequest {
             stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
             stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
             exit_code: output.status.code(),
+            cpu_time: cpu_time.map(Interval::from),
+            wall_time: Some(wall_time.into()),
         };
 
         match self.client.submit_result(task_id, &request).await {
@@ -282,15 +360,88 @@ pub async fn run(client: ApiClient, args: RunArgs) -> ClientResult<()> {
 
     info!("Supported platforms: {}", platform_names.join(", "));
 
+    let host = host_info(host_name(&args)?, platform_ids);
+
+    info!(
+        "Host: {}, CPU: {} ({} cores), memory: {} MiB, OS: {}",
+        host.name,
+        host.cpu_model,
+        host.core_count,
+        host.memory / (1024 * 1024),
+        host.os
+    );
+
+    let project_ids = enabled_project_ids(&client).await?;
+
     Arc::new(ClusterizerClient {
         client,
         args,
-        platform_ids,
+        host,
+        project_ids: Mutex::new(ProjectIds {
+            project_ids,
+            refreshed_at: Instant::now(),
+        }),
+        metadata: Mutex::default(),
     })
     .run()
     .await
 }
 
+async fn enabled_project_ids(client: &ApiClient) -> ClientResult<Vec<Id<Project>>> {
+    Ok(client
+        .get(&ProjectFilter::default().disabled_at(vec![None]))
+        .await?
+        .into_iter()
+        .map(|project| project.id)
+        .collect())
+}
+
+/// The name of the host of this install. Machines can share a hostname or have none, so a random
+/// suffix is added once and kept in the cache directory, which keeps their hosts apart.
+fn host_name(args: &RunArgs) -> ClientResult<String> {
+    let path = args.host_name_file();
+
+    match fs::read_to_string(&path) {
+        Ok(name) if !name.trim().is_empty() => return Ok(name.trim().to_string()),
+        Ok(_) => {}
+        Err(err) if err.kind() == ErrorKind::NotFound => {}
+        Err(err) => Err(err)?,
+    }
+
+    let suffix = format!("{:08x}", RandomState::new().build_hasher().finish() as u32);
+    let name = match System::host_name() {
+        Some(host_name) if !host_name.trim().is_empty() => {
+            format!("{}-{suffix}", host_name.trim())
+        }
+        _ => suffix,
+    };
+
+    fs::write(&path, &name)?;
+
+    Ok(name)
+}
+
+fn host_info(name: String, platform_ids: Vec<Id<Platform>>) -> HostInfo {
+    let system = System::new_with_specifics(
+        RefreshKind::nothing()
+            .with_cpu(CpuRefreshKind::nothing())
+            .with_memory(MemoryRefreshKind::nothing().with_ram()),
+    );
+
+    HostInfo {
+        name,
+        cpu_model: system
+            .cpus()
+            .first()
+            .map(|cpu| cpu.brand().trim().to_string())
+            .unwrap_or_default(),
+        core_count: system.cpus().len() as i32,
+        memory: system.total_memory() as i64,
+        os: System::long_os_version().unwrap_or_else(|| env::consts::OS.to_string()),
+        platform_ids,
+    }
+}
+
 async fn download_archive(file: &File, args: &RunArgs) -> ClientResult<PathBuf> {
     let dir = args.binaries_dir().join(format!("{}", Hex(&file.hash)));
 
@@ -1,13 +1,23 @@
 use args::{ClusterizerArgs, Commands};
 use clap::Parser;
 use clusterizer_api::client::ApiClient;
-use clusterizer_client::result::ClientResult;
-use clusterizer_common::requests::{CreateFileRequest, RegisterRequest};
+use clusterizer_client::result::{ClientError, ClientResult};
+use clusterizer_common::{
+    errors::AuthError,
+    requests::{CreateFileRequest, RegisterRequest, SetBetaOptInRequest},
+};
 use sha2::{Digest, Sha256};
 use tracing::{debug, error, info};
 
+mod admin;
+mod api_key;
 mod args;
+mod assimilate;
 mod client;
+mod member;
+mod process;
+mod stats;
+mod team;
 
 #[tokio::main]
 async fn main() {
@@ -15,6 +25,13 @@ async fn main() {
 
     if let Err(err) = run().await {
         error!("Error: {err}.");
+
+        if let ClientError::Unauthorized(
+            AuthError::MissingApiKey | AuthError::InvalidApiKey | AuthError::RevokedApiKey,
+        ) = err
+        {
+            error!("Pass a valid API key with --api-key, or register again to get a new one.");
+        }
     }
 }
 
@@ -33,6 +50,12 @@ async fn run() -> ClientResult<()> {
             println!("{}", response.api_key);
         }
         Commands::Run(args) => client::run(client, args).await?,
+        Commands::Assimilate(args) => assimilate::assimilate(client, args).await?,
+        Commands::Stats(args) => stats::stats(client, args).await?,
+        Commands::Team(args) => team::team(client, args).await?,
+        Commands::ApiKey(args) => api_key::api_key(client, args).await?,
+        Commands::Member(args) => member::member(client, args).await?,
+        Commands::Admin(args) => admin::admin(client, args).await?,
         Commands::CreateFile(args) => {
             debug!("Creating new file...");
             let bytes = reqwest::get(&args.url)
@@ -52,6 +75,17 @@ async fn run() -> ClientResult<()> {
             println!("{}", response);
             info!("Successfully created new file with ID: {}", response);
         }
+        Commands::SetBetaOptIn(args) => {
+            debug!("Setting beta opt-in...");
+
+            client
+                .set_beta_opt_in(&SetBetaOptInRequest {
+                    beta_opt_in: args.enabled,
+                })
+                .await?;
+
+            info!("Successfully set beta opt-in to {}.", args.enabled);
+        }
     }
 
     Ok(())
@@ -0,0 +1,69 @@
+use clusterizer_api::client::ApiClient;
+use clusterizer_client::result::ClientResult;
+use clusterizer_common::{
+    records::ProjectMemberFilter,
+    requests::{RemoveProjectMemberRequest, SetProjectMemberRequest},
+};
+use tracing::{debug, info};
+
+use crate::args::{MemberArgs, MemberCommands};
+
+pub async fn member(client: ApiClient, args: MemberArgs) -> ClientResult<()> {
+    match args.command {
+        MemberCommands::List(args) => {
+            let members = client
+                .get(&ProjectMemberFilter::default().project_id(vec![args.project_id]))
+                .await?;
+
+            println!("{:>8}  {:<32}  Role", "User", "Name");
+
+            for member in members {
+                let user = client.get(&member.user_id).await?;
+
+                println!(
+                    "{:>8}  {:<32}  {:?}",
+                    user.id.to_string(),
+                    user.name,
+                    member.role
+                );
+            }
+        }
+        MemberCommands::Set(args) => {
+            debug!("Setting project member...");
+
+            client
+                .set_project_member(
+                    args.project_id,
+                    &SetProjectMemberRequest {
+                        user_id: args.user_id,
+                        role: args.role,
+                    },
+                )
+                .await?;
+
+            info!(
+                "Successfully set the role of user {} in project {} to {:?}.",
+                args.user_id, args.project_id, args.role
+            );
+        }
+        MemberCommands::Remove(args) => {
+            debug!("Removing project member...");
+
+            client
+                .remove_project_member(
+                    args.project_id,
+                    &RemoveProjectMemberRequest {
+                        user_id: args.user_id,
+                    },
+                )
+                .await?;
+
+            info!(
+                "Successfully removed user {} from project {}.",
+                args.user_id, args.project_id
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use std::{
+    io::{self, Read, Write},
+    process::{Child, Command, ExitStatus, Output, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use clusterizer_client::result::ClientResult;
+
+pub struct MeasuredOutput {
+    pub output: Output,
+    // None on platforms where the CPU time of a child cannot be measured.
+    pub cpu_time: Option<Duration>,
+    pub wall_time: Duration,
+}
+
+/// Runs the command to completion with the given stdin, measuring the CPU time and wall time of
+/// the child. This uses std processes on a blocking thread, because the CPU time is only
+/// available when reaping the child ourselves.
+pub async fn output(mut command: Command, stdin: String) -> ClientResult<MeasuredOutput> {
+    Ok(tokio::task::spawn_blocking(move || {
+        let start = Instant::now();
+
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let mut child_stdin = child.stdin.take().unwrap();
+        let mut child_stdout = child.stdout.take().unwrap();
+        let mut child_stderr = child.stderr.take().unwrap();
+
+        let (stdout, stderr) = thread::scope(|scope| {
+            scope.spawn(move || child_stdin.write_all(stdin.as_bytes()));
+
+            let stdout = scope.spawn(move || {
+                let mut stdout = Vec::new();
+                child_stdout.read_to_end(&mut stdout).map(|_| stdout)
+            });
+
+            let mut stderr = Vec::new();
+            child_stderr.read_to_end(&mut stderr)?;
+
+            io::Result::Ok((stdout.join().unwrap()?, stderr))
+        })?;
+
+        let (status, cpu_time) = wait(&mut child)?;
+
+        io::Result::Ok(MeasuredOutput {
+            output: Output {
+                status,
+                stdout,
+                stderr,
+            },
+            cpu_time,
+            wall_time: start.elapsed(),
+        })
+    })
+    .await??)
+}
+
+#[cfg(unix)]
+fn wait(child: &mut Child) -> io::Result<(ExitStatus, Option<Duration>)> {
+    use std::{mem, os::unix::process::ExitStatusExt};
+
+    let mut status = 0;
+    // SAFETY: rusage is plain old data, for which all zeroes is a valid value.
+    let mut rusage: libc::rusage = unsafe { mem::zeroed() };
+
+    loop {
+        // SAFETY: the pointers are valid for the duration of the call, and the child has not
+        // been reaped yet.
+        let pid = unsafe { libc::wait4(child.id() as libc::pid_t, &mut status, 0, &mut rusage) };
+
+        if pid != -1 {
+            break;
+        }
+
+        let err = io::Error::last_os_error();
+
+        if err.kind() != io::ErrorKind::Interrupted {
+            return Err(err);
+        }
+    }
+
+    let duration = |time: libc::timeval| {
+        Duration::from_secs(time.tv_sec as u64) + Duration::from_micros(time.tv_usec as u64)
+    };
+
+    Ok((
+        ExitStatus::from_raw(status),
+        Some(duration(rusage.ru_utime) + duration(rusage.ru_stime)),
+    ))
+}
+
+#[cfg(not(unix))]
+fn wait(child: &mut Child) -> io::Result<(ExitStatus, Option<Duration>)> {
+    Ok((child.wait()?, None))
+}
@@ -0,0 +1,100 @@
+use clusterizer_api::client::ApiClient;
+use clusterizer_client::result::ClientResult;
+use clusterizer_common::requests::{TeamStatsRequest, UserStatsRequest};
+
+use crate::args::{StatsArgs, StatsCommands};
+
+pub async fn stats(client: ApiClient, args: StatsArgs) -> ClientResult<()> {
+    match args.command {
+        StatsCommands::Users(args) => {
+            let user_stats = client
+                .user_stats(&UserStatsRequest {
+                    offset: Some(args.offset),
+                    limit: Some(args.limit),
+                })
+                .await?;
+
+            println!(
+                "{:>6}  {:<32}  {:>14}  {:>14}  {:>10}  {:>8}",
+                "Rank", "Name", "Credit", "Credit (7d)", "Valid", "Hosts"
+            );
+
+            for user in user_stats {
+                println!(
+                    "{:>6}  {:<32}  {:>14}  {:>14}  {:>10}  {:>8}",
+                    user.rank,
+                    user.name,
+                    user.total_credit,
+                    user.recent_credit,
+                    user.valid_result_count,
+                    user.active_host_count
+                );
+            }
+        }
+        StatsCommands::Teams(args) => {
+            let team_stats = client
+                .team_stats(&TeamStatsRequest {
+                    offset: Some(args.offset),
+                    limit: Some(args.limit),
+                })
+                .await?;
+
+            println!(
+                "{:>6}  {:<32}  {:>14}  {:>14}  {:>10}  {:>8}",
+                "Rank", "Name", "Credit", "Credit (7d)", "Valid", "Members"
+            );
+
+            for team in team_stats {
+                println!(
+                    "{:>6}  {:<32}  {:>14}  {:>14}  {:>10}  {:>8}",
+                    team.rank,
+                    team.name,
+                    team.total_credit,
+                    team.recent_credit,
+                    team.valid_result_count,
+                    team.member_count
+                );
+            }
+        }
+        StatsCommands::Project(args) => {
+            let project = client.project_stats(args.project_id).await?;
+
+            println!(
+                "Project:              {} ({})",
+                project.name, project.project_id
+            );
+            println!("Tasks:                {}", project.task_count);
+            println!("Remaining tasks:      {}", project.remaining_task_count);
+            println!("Valid tasks:          {}", project.valid_task_count);
+            println!("Failed tasks:         {}", project.failed_task_count);
+            println!(
+                "In progress:          {}",
+                project.in_progress_assignment_count
+            );
+            println!("Active users:         {}", project.active_user_count);
+            println!("Credit:               {}", project.total_credit);
+            println!("Updated at:           {}", project.refreshed_at);
+        }
+        StatsCommands::Global => {
+            let global = client.global_stats().await?;
+
+            println!("Users:                {}", global.user_count);
+            println!("Active users:         {}", global.active_user_count);
+            println!("Active hosts:         {}", global.active_host_count);
+            println!("Projects:             {}", global.project_count);
+            println!("Tasks:                {}", global.task_count);
+            println!("Remaining tasks:      {}", global.remaining_task_count);
+            println!(
+                "In progress:          {}",
+                global.in_progress_assignment_count
+            );
+            println!("Credit:               {}", global.total_credit);
+
+            if let Some(refreshed_at) = global.refreshed_at {
+                println!("Updated at:           {refreshed_at}");
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use clusterizer_api::client::ApiClient;
+use clusterizer_client::result::ClientResult;
+use clusterizer_common::requests::{CreateTeamRequest, KickTeamMemberRequest};
+use tracing::{debug, info};
+
+use crate::args::{TeamArgs, TeamCommands};
+
+pub async fn team(client: ApiClient, args: TeamArgs) -> ClientResult<()> {
+    match args.command {
+        TeamCommands::Create(args) => {
+            debug!("Creating team...");
+
+            let team_id = client
+                .create_team(&CreateTeamRequest { name: args.name })
+                .await?;
+
+            println!("{team_id}");
+            info!("Successfully created team with ID: {team_id}");
+        }
+        TeamCommands::Join(args) => {
+            debug!("Joining team...");
+
+            client.join_team(args.team_id).await?;
+
+            info!("Successfully joined team {}.", args.team_id);
+        }
+        TeamCommands::Leave => {
+            debug!("Leaving team...");
+
+            client.leave_team().await?;
+
+            info!("Successfully left team.");
+        }
+        TeamCommands::Kick(args) => {
+            debug!("Removing team member...");
+
+            client
+                .kick_team_member(
+                    args.team_id,
+                    &KickTeamMemberRequest {
+                        user_id: args.user_id,
+                    },
+                )
+                .await?;
+
+            info!(
+                "Successfully removed user {} from team {}.",
+                args.user_id, args.team_id
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use std::{error::Error, io};
 
 use clusterizer_api::result::ApiError;
+use clusterizer_common::errors::AuthError;
 use thiserror::Error;
 use tokio::task::JoinError;
 use zip::result::ZipError;
@@ -9,6 +10,7 @@ use zip::result::ZipError;
 #[error(transparent)]
 pub enum ClientError {
     Specific(Box<dyn Error + Sync + Send>),
+    Unauthorized(AuthError),
     Reqwest(#[from] reqwest::Error),
     Zip(#[from] ZipError),
     Io(#[from] io::Error),
@@ -21,6 +23,7 @@ impl<E: Error + Sync + Send + 'static> From<ApiError<E>> for ClientError {
     fn from(err: ApiError<E>) -> Self {
         match err {
             ApiError::Specific(err) => Self::Specific(Box::new(err)),
+            ApiError::Unauthorized(err) => Self::Unauthorized(err),
             ApiError::String(err) => Self::Specific(err.into()),
             ApiError::Reqwest(err) => Self::Reqwest(err),
         }
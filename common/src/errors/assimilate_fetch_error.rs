@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, Error)]
+pub enum AssimilateFetchError {
+    #[error("forbidden")]
+    Forbidden,
+    #[error("invalid project")]
+    InvalidProject,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, Error)]
+pub enum AssimilateSubmitError {
+    #[error("forbidden")]
+    Forbidden,
+    #[error("invalid project")]
+    InvalidProject,
+    #[error("invalid task")]
+    InvalidTask,
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Why a request could not be authenticated. Sent with 401 if the credentials are missing or
+/// invalid, and with 403 if they are valid but not allowed.
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, Error)]
+pub enum AuthError {
+    #[error("no api key provided")]
+    MissingApiKey,
+    #[error("api key is invalid")]
+    InvalidApiKey,
+    #[error("api key is revoked")]
+    RevokedApiKey,
+    #[error("api key lacks the required scope")]
+    MissingScope,
+    #[error("user is disabled")]
+    UserDisabled,
+}
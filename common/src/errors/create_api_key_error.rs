@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, Error)]
+pub enum CreateApiKeyError {
+    #[error("scope is not granted to the api key in use")]
+    ScopeNotGranted,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, Error)]
+pub enum CreateTeamError {
+    #[error("name too short")]
+    TooShort,
+    #[error("name too long")]
+    TooLong,
+    #[error("name includes invalid character")]
+    InvalidCharacter,
+    #[error("team already exists with that name")]
+    AlreadyExists,
+    #[error("founder of another team with members")]
+    FounderOfTeam,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, Error)]
+pub enum CreateWebhookError {
+    #[error("forbidden")]
+    Forbidden,
+    #[error("invalid project")]
+    InvalidProject,
+    #[error("url is invalid")]
+    InvalidUrl,
+    #[error("no events")]
+    NoEvents,
+}
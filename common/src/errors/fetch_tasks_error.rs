@@ -5,4 +5,12 @@ use thiserror::Error;
 pub enum FetchTasksError {
     #[error("invalid project")]
     InvalidProject,
+    #[error("invalid host, its name cannot be empty")]
+    InvalidHost,
+    #[error("too many assignments in progress, submit results before fetching more tasks")]
+    InProgressLimitReached,
+    #[error("daily quota reached, valid results raise the quota again")]
+    DailyQuotaReached,
+    #[error("too many assignments of the project in progress, try again later")]
+    ProjectInProgressLimitReached,
 }
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, Error)]
+pub enum JoinTeamError {
+    #[error("invalid team")]
+    InvalidTeam,
+    #[error("founder of another team with members")]
+    FounderOfTeam,
+}
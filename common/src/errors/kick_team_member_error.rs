@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, Error)]
+pub enum KickTeamMemberError {
+    #[error("forbidden")]
+    Forbidden,
+    #[error("invalid team")]
+    InvalidTeam,
+    #[error("user is not a member of the team")]
+    NotMember,
+}
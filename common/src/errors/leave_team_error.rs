@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, Error)]
+pub enum LeaveTeamError {
+    #[error("not in a team")]
+    NotInTeam,
+    #[error("founder of a team with members")]
+    FounderOfTeam,
+}
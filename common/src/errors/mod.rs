@@ -1,17 +1,49 @@
+pub mod assimilate_fetch_error;
+pub mod assimilate_submit_error;
+pub mod auth_error;
+pub mod create_api_key_error;
 pub mod create_file_error;
+pub mod create_team_error;
+pub mod create_webhook_error;
 pub mod fetch_tasks_error;
 pub mod infallible;
+pub mod join_team_error;
+pub mod kick_team_member_error;
+pub mod leave_team_error;
 pub mod not_found;
 pub mod register_error;
+pub mod remove_project_member_error;
+pub mod revoke_api_key_error;
+pub mod set_project_member_error;
+pub mod set_task_priority_error;
+pub mod set_user_admin_error;
+pub mod set_user_disabled_error;
 pub mod submit_result_error;
+pub mod user_activity_error;
 pub mod validate_fetch_error;
 pub mod validate_submit_error;
 
+pub use assimilate_fetch_error::AssimilateFetchError;
+pub use assimilate_submit_error::AssimilateSubmitError;
+pub use auth_error::AuthError;
+pub use create_api_key_error::CreateApiKeyError;
 pub use create_file_error::CreateFileError;
+pub use create_team_error::CreateTeamError;
+pub use create_webhook_error::CreateWebhookError;
 pub use fetch_tasks_error::FetchTasksError;
 pub use infallible::Infallible;
+pub use join_team_error::JoinTeamError;
+pub use kick_team_member_error::KickTeamMemberError;
+pub use leave_team_error::LeaveTeamError;
 pub use not_found::NotFound;
 pub use register_error::RegisterError;
+pub use remove_project_member_error::RemoveProjectMemberError;
+pub use revoke_api_key_error::RevokeApiKeyError;
+pub use set_project_member_error::SetProjectMemberError;
+pub use set_task_priority_error::SetTaskPriorityError;
+pub use set_user_admin_error::SetUserAdminError;
+pub use set_user_disabled_error::SetUserDisabledError;
 pub use submit_result_error::SubmitResultError;
+pub use user_activity_error::UserActivityError;
 pub use validate_fetch_error::ValidateFetchError;
 pub use validate_submit_error::ValidateSubmitError;
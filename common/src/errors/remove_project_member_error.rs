@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, Error)]
+pub enum RemoveProjectMemberError {
+    #[error("forbidden")]
+    Forbidden,
+    #[error("invalid project")]
+    InvalidProject,
+    #[error("user is not a member of the project")]
+    NotMember,
+    #[error("project would be left without an owner")]
+    LastOwner,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, Error)]
+pub enum RevokeApiKeyError {
+    #[error("invalid api key")]
+    InvalidApiKey,
+    #[error("api key is already revoked")]
+    AlreadyRevoked,
+}
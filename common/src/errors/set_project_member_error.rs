@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, Error)]
+pub enum SetProjectMemberError {
+    #[error("forbidden")]
+    Forbidden,
+    #[error("invalid project")]
+    InvalidProject,
+    #[error("invalid user")]
+    InvalidUser,
+    #[error("project would be left without an owner")]
+    LastOwner,
+}
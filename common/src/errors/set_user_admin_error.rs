@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, Error)]
+pub enum SetUserAdminError {
+    #[error("forbidden")]
+    Forbidden,
+    #[error("invalid user")]
+    InvalidUser,
+    #[error("there would be no admins left")]
+    LastAdmin,
+}
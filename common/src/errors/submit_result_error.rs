@@ -11,4 +11,6 @@ pub enum SubmitResultError {
     AssignmentCanceled,
     #[error("assignment expired")]
     AssignmentExpired,
+    #[error("invalid cpu time")]
+    InvalidCpuTime,
 }
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    records::{User, record_impl},
+    types::{Id, Scope},
+};
+
+record_impl! {
+    PATH = "api_keys";
+
+    ApiKey {
+        id: Id<ApiKey>,
+        created_at: DateTime<Utc>,
+        revoked_at: Option<DateTime<Utc>>,
+        user_id: Id<User>,
+        scopes: Option<Vec<Scope>>,
+    }
+
+    ApiKeyFilter {
+        "$1::int8[] IS NULL OR array_position($1, id) IS NOT NULL"
+        id: Vec<Id<ApiKey>>,
+        "$2::timestamptz[] IS NULL OR array_position($2, created_at) IS NOT NULL"
+        created_at: Vec<DateTime<Utc>>,
+        "$3::timestamptz[] IS NULL OR array_position($3, revoked_at) IS NOT NULL"
+        revoked_at: Vec<Option<DateTime<Utc>>>,
+        "$4::int8[] IS NULL OR array_position($4, user_id) IS NOT NULL"
+        user_id: Vec<Id<User>>,
+    }
+
+    ApiKeyBuilder {
+        "user_id" "$1"
+        user_id: Id<User>,
+        "scopes" "$2"
+        scopes: Option<Vec<Scope>>,
+    }
+
+    UpdateApiKey {
+        update_revoked_at("revoked_at" Option<DateTime<Utc>>);
+    }
+}
@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    records::{Task, User, record_impl},
+    records::{Host, ProjectVersion, Task, User, record_impl},
     types::{AssignmentState, Id},
 };
 
@@ -16,6 +16,8 @@ record_impl! {
         task_id: Id<Task>,
         user_id: Id<User>,
         state: AssignmentState,
+        host_id: Option<Id<Host>>,
+        project_version_id: Option<Id<ProjectVersion>>,
     }
 
     AssignmentFilter {
@@ -31,6 +33,10 @@ record_impl! {
         user_id: Vec<Id<User>>,
         "$6::assignment_state[] IS NULL OR array_position($6, state) IS NOT NULL"
         state: Vec<AssignmentState>,
+        "$7::int8[] IS NULL OR array_position($7, host_id) IS NOT NULL"
+        host_id: Vec<Option<Id<Host>>>,
+        "$8::int8[] IS NULL OR array_position($8, project_version_id) IS NOT NULL"
+        project_version_id: Vec<Option<Id<ProjectVersion>>>,
     }
 
     AssignmentBuilder {
@@ -38,6 +44,10 @@ record_impl! {
         task_id: Id<Task>,
         "user_id" "$2"
         user_id: Id<User>,
+        "host_id" "$3"
+        host_id: Id<Host>,
+        "project_version_id" "$4"
+        project_version_id: Id<ProjectVersion>,
     }
 
     UpdateAssignment {
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    records::{Project, Result, User, record_impl},
+    types::Id,
+};
+
+record_impl! {
+    PATH = "credits";
+
+    Credit {
+        id: Id<Credit>,
+        created_at: DateTime<Utc>,
+        user_id: Id<User>,
+        project_id: Id<Project>,
+        result_id: Id<Result>,
+        amount: i64,
+    }
+
+    CreditFilter {
+        "$1::int8[] IS NULL OR array_position($1, id) IS NOT NULL"
+        id: Vec<Id<Credit>>,
+        "$2::timestamptz[] IS NULL OR array_position($2, created_at) IS NOT NULL"
+        created_at: Vec<DateTime<Utc>>,
+        "$3::int8[] IS NULL OR array_position($3, user_id) IS NOT NULL"
+        user_id: Vec<Id<User>>,
+        "$4::int8[] IS NULL OR array_position($4, project_id) IS NOT NULL"
+        project_id: Vec<Id<Project>>,
+        "$5::int8[] IS NULL OR array_position($5, result_id) IS NOT NULL"
+        result_id: Vec<Id<Result>>,
+        "$6::int8[] IS NULL OR array_position($6, amount) IS NOT NULL"
+        amount: Vec<i64>,
+    }
+
+    CreditBuilder {
+        "user_id" "$1"
+        user_id: Id<User>,
+        "project_id" "$2"
+        project_id: Id<Project>,
+        "result_id" "$3"
+        result_id: Id<Result>,
+        "amount" "$4"
+        amount: i64,
+    }
+
+    UpdateCredit {}
+}
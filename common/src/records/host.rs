@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    records::{Platform, User, record_impl},
+    types::Id,
+};
+
+record_impl! {
+    PATH = "hosts";
+
+    Host {
+        id: Id<Host>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+        user_id: Id<User>,
+        name: String,
+        cpu_model: String,
+        core_count: i32,
+        memory: i64,
+        os: String,
+        platform_ids: Vec<Id<Platform>>,
+    }
+
+    HostFilter {
+        "$1::int8[] IS NULL OR array_position($1, id) IS NOT NULL"
+        id: Vec<Id<Host>>,
+        "$2::timestamptz[] IS NULL OR array_position($2, created_at) IS NOT NULL"
+        created_at: Vec<DateTime<Utc>>,
+        "$3::timestamptz[] IS NULL OR array_position($3, updated_at) IS NOT NULL"
+        updated_at: Vec<DateTime<Utc>>,
+        "$4::int8[] IS NULL OR array_position($4, user_id) IS NOT NULL"
+        user_id: Vec<Id<User>>,
+        "$5::text[] IS NULL OR array_position($5, name) IS NOT NULL"
+        name: Vec<String>,
+        "$6::text[] IS NULL OR array_position($6, cpu_model) IS NOT NULL"
+        cpu_model: Vec<String>,
+        "$7::int4[] IS NULL OR array_position($7, core_count) IS NOT NULL"
+        core_count: Vec<i32>,
+        "$8::int8[] IS NULL OR array_position($8, memory) IS NOT NULL"
+        memory: Vec<i64>,
+        "$9::text[] IS NULL OR array_position($9, os) IS NOT NULL"
+        os: Vec<String>,
+    }
+
+    HostBuilder {
+        "user_id" "$1"
+        user_id: Id<User>,
+        "name" "$2"
+        name: String,
+        "cpu_model" "$3"
+        cpu_model: String,
+        "core_count" "$4"
+        core_count: i32,
+        "memory" "$5"
+        memory: i64,
+        "os" "$6"
+        os: String,
+        "platform_ids" "$7"
+        platform_ids: Vec<Id<Platform>>,
+    }
+
+    UpdateHost {}
+}
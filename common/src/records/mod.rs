@@ -1,20 +1,36 @@
+pub mod api_key;
 pub mod assignment;
+pub mod credit;
 pub mod file;
+pub mod host;
 pub mod platform;
 pub mod project;
+pub mod project_member;
 pub mod project_version;
 pub mod result;
 pub mod task;
+pub mod team;
 pub mod user;
+pub mod user_quota;
+pub mod webhook;
+pub mod webhook_delivery;
 
+pub use api_key::{ApiKey, ApiKeyBuilder, ApiKeyFilter};
 pub use assignment::{Assignment, AssignmentBuilder, AssignmentFilter};
+pub use credit::{Credit, CreditBuilder, CreditFilter};
 pub use file::{File, FileBuilder, FileFilter};
+pub use host::{Host, HostBuilder, HostFilter};
 pub use platform::{Platform, PlatformBuilder, PlatformFilter};
 pub use project::{Project, ProjectBuilder, ProjectFilter};
+pub use project_member::{ProjectMember, ProjectMemberBuilder, ProjectMemberFilter};
 pub use project_version::{ProjectVersion, ProjectVersionBuilder, ProjectVersionFilter};
 pub use result::{Result, ResultBuilder, ResultFilter};
 pub use task::{Task, TaskBuilder, TaskFilter};
+pub use team::{Team, TeamBuilder, TeamFilter};
 pub use user::{User, UserBuilder, UserFilter};
+pub use user_quota::{UserQuota, UserQuotaBuilder, UserQuotaFilter};
+pub use webhook::{Webhook, WebhookBuilder, WebhookFilter};
+pub use webhook_delivery::{WebhookDelivery, WebhookDeliveryBuilder, WebhookDeliveryFilter};
 
 #[cfg(feature = "sqlx")]
 mod sqlx {
@@ -59,6 +75,59 @@ pub trait Insert {
 }
 
 macro_rules! record_impl {
+    // The value is bound after the filter parameters, of which there can be up to 31.
+    (
+        @filter_updates
+        $table_name_literal:literal $filter_ident:ident $conditions:tt $fields:tt
+        $([$($update_fn_filter_ident:ident)?] $update_fn_ident:ident $update_fn_name_literal:literal $update_fn_ty:ty;)*
+    ) => {
+        #[cfg(feature = "sqlx")]
+        impl $filter_ident {
+            $(
+                record_impl!(
+                    @filter_update_fn [$($update_fn_filter_ident)?]
+                    ["$1" "$2" "$3" "$4" "$5" "$6" "$7" "$8" "$9" "$10" "$11" "$12" "$13" "$14" "$15" "$16" "$17" "$18" "$19" "$20" "$21" "$22" "$23" "$24" "$25" "$26" "$27" "$28" "$29" "$30" "$31" "$32"]
+                    $fields
+                    $table_name_literal $conditions $fields
+                    $update_fn_ident $update_fn_name_literal $update_fn_ty
+                );
+            )*
+        }
+    };
+
+    (@filter_update_fn [] $($rest:tt)*) => {};
+
+    // Drops a placeholder for each filter parameter, so the first one left is bound to the value.
+    (
+        @filter_update_fn [filter]
+        [$_placeholder_literal:literal $($placeholder_literal:literal)*]
+        [$_counted_field_ident:ident $($counted_field_ident:ident)*]
+        $($rest:tt)*
+    ) => {
+        record_impl!(
+            @filter_update_fn [filter]
+            [$($placeholder_literal)*]
+            [$($counted_field_ident)*]
+            $($rest)*
+        );
+    };
+
+    (
+        @filter_update_fn [filter]
+        [$value_literal:literal $($_placeholder_literal:literal)*]
+        []
+        $table_name_literal:literal [$($condition:tt)*] [$($field_ident:ident)*]
+        $update_fn_ident:ident $update_fn_name_literal:literal $update_fn_ty:ty
+    ) => {
+        pub fn $update_fn_ident(&self, value: $update_fn_ty) -> $crate::records::sqlx::Query {
+            sqlx::query_unchecked!(
+                "UPDATE " + $table_name_literal + " SET " + $update_fn_name_literal + " = " + $value_literal + " WHERE TRUE" $($condition)*,
+                $(self.$field_ident,)*
+                value,
+            )
+        }
+    };
+
     (
         PATH = $table_name_literal:literal;
 
@@ -85,7 +154,7 @@ macro_rules! record_impl {
         }
 
         $update_ident:ident {
-            $($update_fn_ident:ident($update_fn_name_literal:literal $update_fn_ty:ty);)*
+            $($update_fn_ident:ident($update_fn_name_literal:literal $update_fn_ty:ty) $($update_fn_filter_ident:ident)?;)*
         }
     ) => {
         #[cfg(feature = "sqlx")]
@@ -226,6 +295,15 @@ macro_rules! record_impl {
                 }
             )*
         }
+
+        // Updating by filter is opt-in per field, by following it with `filter`.
+        record_impl!(
+            @filter_updates
+            $table_name_literal $filter_ident
+            [$(+ " AND (" + $filter_field_condition_literal + ")")*]
+            [$($filter_field_ident)*]
+            $([$($update_fn_filter_ident)?] $update_fn_ident $update_fn_name_literal $update_fn_ty;)*
+        );
     };
 }
 
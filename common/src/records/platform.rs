@@ -14,6 +14,7 @@ record_impl! {
         created_at: DateTime<Utc>,
         name: String,
         file_id: Id<File>,
+        hr_class: Option<String>,
     }
 
     PlatformFilter {
@@ -25,6 +26,8 @@ record_impl! {
         name: Vec<String>,
         "$4::int8[] IS NULL OR array_position($4, file_id) IS NOT NULL"
         file_id: Vec<Id<File>>,
+        "$5::text[] IS NULL OR array_position($5, hr_class) IS NOT NULL"
+        hr_class: Vec<Option<String>>,
     }
 
     PlatformBuilder {
@@ -32,6 +35,8 @@ record_impl! {
         name: String,
         "file_id" "$2"
         file_id: Id<File>,
+        "hr_class" "$3"
+        hr_class: Option<String>,
     }
 
     UpdatePlatform {}
@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    records::{User, record_impl},
+    records::{Platform, User, record_impl},
     types::Id,
 };
 
@@ -15,6 +15,21 @@ record_impl! {
         created_by_user_id: Id<User>,
         disabled_at: Option<DateTime<Utc>>,
         name: String,
+        min_core_count: Option<i32>,
+        min_memory: Option<i64>,
+        required_platform_id: Option<Id<Platform>>,
+        homogeneous_redundancy: bool,
+        adaptive_replication_threshold: Option<i32>,
+        adaptive_replication_spot_check_percent: i32,
+        share: i32,
+        follow_up_project_id: Option<Id<Project>>,
+        follow_up_program: Option<String>,
+        max_error_results: Option<i32>,
+        credit_per_result: i64,
+        credit_per_cpu_hour: i64,
+        max_in_progress_per_user: Option<i32>,
+        daily_quota: Option<i32>,
+        max_in_progress: Option<i32>,
     }
 
     ProjectFilter {
@@ -28,6 +43,36 @@ record_impl! {
         disabled_at: Vec<Option<DateTime<Utc>>>,
         "$5::text[] IS NULL OR array_position($5, name) IS NOT NULL"
         name: Vec<String>,
+        "$6::int4[] IS NULL OR array_position($6, min_core_count) IS NOT NULL"
+        min_core_count: Vec<Option<i32>>,
+        "$7::int8[] IS NULL OR array_position($7, min_memory) IS NOT NULL"
+        min_memory: Vec<Option<i64>>,
+        "$8::int8[] IS NULL OR array_position($8, required_platform_id) IS NOT NULL"
+        required_platform_id: Vec<Option<Id<Platform>>>,
+        "$9::bool[] IS NULL OR array_position($9, homogeneous_redundancy) IS NOT NULL"
+        homogeneous_redundancy: Vec<bool>,
+        "$10::int4[] IS NULL OR array_position($10, adaptive_replication_threshold) IS NOT NULL"
+        adaptive_replication_threshold: Vec<Option<i32>>,
+        "$11::int4[] IS NULL OR array_position($11, adaptive_replication_spot_check_percent) IS NOT NULL"
+        adaptive_replication_spot_check_percent: Vec<i32>,
+        "$12::int4[] IS NULL OR array_position($12, share) IS NOT NULL"
+        share: Vec<i32>,
+        "$13::int8[] IS NULL OR array_position($13, follow_up_project_id) IS NOT NULL"
+        follow_up_project_id: Vec<Option<Id<Project>>>,
+        "$14::text[] IS NULL OR array_position($14, follow_up_program) IS NOT NULL"
+        follow_up_program: Vec<Option<String>>,
+        "$15::int4[] IS NULL OR array_position($15, max_error_results) IS NOT NULL"
+        max_error_results: Vec<Option<i32>>,
+        "$16::int8[] IS NULL OR array_position($16, credit_per_result) IS NOT NULL"
+        credit_per_result: Vec<i64>,
+        "$17::int8[] IS NULL OR array_position($17, credit_per_cpu_hour) IS NOT NULL"
+        credit_per_cpu_hour: Vec<i64>,
+        "$18::int4[] IS NULL OR array_position($18, max_in_progress_per_user) IS NOT NULL"
+        max_in_progress_per_user: Vec<Option<i32>>,
+        "$19::int4[] IS NULL OR array_position($19, daily_quota) IS NOT NULL"
+        daily_quota: Vec<Option<i32>>,
+        "$20::int4[] IS NULL OR array_position($20, max_in_progress) IS NOT NULL"
+        max_in_progress: Vec<Option<i32>>,
     }
 
     ProjectBuilder {
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    records::{Project, User, record_impl},
+    types::{Id, ProjectRole},
+};
+
+record_impl! {
+    PATH = "project_members";
+
+    ProjectMember {
+        id: Id<ProjectMember>,
+        created_at: DateTime<Utc>,
+        project_id: Id<Project>,
+        user_id: Id<User>,
+        role: ProjectRole,
+    }
+
+    ProjectMemberFilter {
+        "$1::int8[] IS NULL OR array_position($1, id) IS NOT NULL"
+        id: Vec<Id<ProjectMember>>,
+        "$2::timestamptz[] IS NULL OR array_position($2, created_at) IS NOT NULL"
+        created_at: Vec<DateTime<Utc>>,
+        "$3::int8[] IS NULL OR array_position($3, project_id) IS NOT NULL"
+        project_id: Vec<Id<Project>>,
+        "$4::int8[] IS NULL OR array_position($4, user_id) IS NOT NULL"
+        user_id: Vec<Id<User>>,
+        "$5::project_role[] IS NULL OR array_position($5, role) IS NOT NULL"
+        role: Vec<ProjectRole>,
+    }
+
+    ProjectMemberBuilder {
+        "project_id" "$1"
+        project_id: Id<Project>,
+        "user_id" "$2"
+        user_id: Id<User>,
+        "role" "$3"
+        role: ProjectRole,
+    }
+
+    UpdateProjectMember {
+        update_role("role" ProjectRole);
+    }
+}
@@ -16,6 +16,8 @@ record_impl! {
         project_id: Id<Project>,
         platform_id: Id<Platform>,
         file_id: Id<File>,
+        version: i32,
+        is_beta: bool,
     }
 
     ProjectVersionFilter {
@@ -31,6 +33,10 @@ record_impl! {
         platform_id: Vec<Id<Platform>>,
         "$6::int8[] IS NULL OR array_position($6, file_id) IS NOT NULL"
         file_id: Vec<Id<File>>,
+        "$7::int4[] IS NULL OR array_position($7, version) IS NOT NULL"
+        version: Vec<i32>,
+        "$8::bool[] IS NULL OR array_position($8, is_beta) IS NOT NULL"
+        is_beta: Vec<bool>,
     }
 
     ProjectVersionBuilder {
@@ -40,6 +46,10 @@ record_impl! {
         platform_id: Id<Platform>,
         "file_id" "$3"
         file_id: Id<File>,
+        "version" "$4"
+        version: i32,
+        "is_beta" "$5"
+        is_beta: bool,
     }
 
     UpdateProjectVersion {}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     records::{Assignment, record_impl},
-    types::{Id, ResultState},
+    types::{Id, Interval, ResultState},
 };
 
 record_impl! {
@@ -18,6 +18,8 @@ record_impl! {
         exit_code: Option<i32>,
         group_result_id: Option<Id<Result>>,
         state: ResultState,
+        cpu_time: Option<Interval>,
+        wall_time: Option<Interval>,
     }
 
     ResultFilter {
@@ -37,6 +39,10 @@ record_impl! {
         group_result_id: Vec<Option<Id<Result>>>,
         "$8::result_state[] IS NULL OR array_position($8, state) IS NOT NULL"
         state: Vec<ResultState>,
+        "$9::interval[] IS NULL OR array_position($9, cpu_time) IS NOT NULL"
+        cpu_time: Vec<Option<Interval>>,
+        "$10::interval[] IS NULL OR array_position($10, wall_time) IS NOT NULL"
+        wall_time: Vec<Option<Interval>>,
     }
 
     ResultBuilder {
@@ -48,6 +54,10 @@ record_impl! {
         stderr: String,
         "exit_code" "$4"
         exit_code: Option<i32>,
+        "cpu_time" "$5"
+        cpu_time: Option<Interval>,
+        "wall_time" "$6"
+        wall_time: Option<Interval>,
     }
 
     UpdateResult {
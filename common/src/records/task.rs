@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    records::{Project, User, record_impl},
+    records::{Project, Result, User, record_impl},
     types::{Id, Interval},
 };
 
@@ -18,6 +18,16 @@ record_impl! {
         assignments_needed: i32,
         assignment_user_ids: Vec<Id<User>>,
         quorum: i32,
+        hr_class: Option<String>,
+        canonical_result_id: Option<Id<Result>>,
+        canonical_hr_class: Option<String>,
+        priority: i32,
+        dependency_task_ids: Vec<Id<Task>>,
+        follow_up_created_at: Option<DateTime<Utc>>,
+        follow_up_attempt_count: i32,
+        follow_up_attempted_at: Option<DateTime<Utc>>,
+        assimilated_at: Option<DateTime<Utc>>,
+        failed_at: Option<DateTime<Utc>>,
     }
 
     TaskFilter {
@@ -35,6 +45,20 @@ record_impl! {
         assignments_needed: Vec<i32>,
         "$7::int8[] IS NULL OR array_position($7, quorum) IS NOT NULL"
         quorum: Vec<i32>,
+        "$8::text[] IS NULL OR array_position($8, hr_class) IS NOT NULL"
+        hr_class: Vec<Option<String>>,
+        "$9::int8[] IS NULL OR array_position($9, canonical_result_id) IS NOT NULL"
+        canonical_result_id: Vec<Option<Id<Result>>>,
+        "$10::text[] IS NULL OR array_position($10, canonical_hr_class) IS NOT NULL"
+        canonical_hr_class: Vec<Option<String>>,
+        "$11::int4[] IS NULL OR array_position($11, priority) IS NOT NULL"
+        priority: Vec<i32>,
+        "$12::timestamptz[] IS NULL OR array_position($12, follow_up_created_at) IS NOT NULL"
+        follow_up_created_at: Vec<Option<DateTime<Utc>>>,
+        "$13::timestamptz[] IS NULL OR array_position($13, assimilated_at) IS NOT NULL"
+        assimilated_at: Vec<Option<DateTime<Utc>>>,
+        "$14::timestamptz[] IS NULL OR array_position($14, failed_at) IS NOT NULL"
+        failed_at: Vec<Option<DateTime<Utc>>>,
     }
 
     TaskBuilder {
@@ -46,9 +70,19 @@ record_impl! {
         stdin: String,
         "quorum" "$4"
         quorum: i32,
+        "priority" "$5"
+        priority: i32,
+        "dependency_task_ids" "$6"
+        dependency_task_ids: Vec<Id<Task>>,
     }
 
     UpdateTask {
         update_assignments_needed("assignments_needed" i32);
+        update_hr_class("hr_class" Option<String>);
+        update_canonical_result_id("canonical_result_id" Option<Id<Result>>);
+        update_canonical_hr_class("canonical_hr_class" Option<String>);
+        update_priority("priority" i32) filter;
+        update_follow_up_created_at("follow_up_created_at" Option<DateTime<Utc>>);
+        update_failed_at("failed_at" Option<DateTime<Utc>>);
     }
 }
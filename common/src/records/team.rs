@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    records::{User, record_impl},
+    types::Id,
+};
+
+record_impl! {
+    PATH = "teams";
+
+    Team {
+        id: Id<Team>,
+        created_at: DateTime<Utc>,
+        founder_user_id: Id<User>,
+        name: String,
+    }
+
+    TeamFilter {
+        "$1::int8[] IS NULL OR array_position($1, id) IS NOT NULL"
+        id: Vec<Id<Team>>,
+        "$2::timestamptz[] IS NULL OR array_position($2, created_at) IS NOT NULL"
+        created_at: Vec<DateTime<Utc>>,
+        "$3::int8[] IS NULL OR array_position($3, founder_user_id) IS NOT NULL"
+        founder_user_id: Vec<Id<User>>,
+        "$4::text[] IS NULL OR array_position($4, name) IS NOT NULL"
+        name: Vec<String>,
+    }
+
+    TeamBuilder {
+        "founder_user_id" "$1"
+        founder_user_id: Id<User>,
+        "name" "$2"
+        name: String,
+    }
+
+    UpdateTeam {}
+}
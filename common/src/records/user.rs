@@ -1,7 +1,10 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::{records::record_impl, types::Id};
+use crate::{
+    records::{Team, record_impl},
+    types::Id,
+};
 
 record_impl! {
     PATH = "users";
@@ -12,6 +15,12 @@ record_impl! {
         disabled_at: Option<DateTime<Utc>>,
         name: String,
         is_admin: bool,
+        beta_opt_in: bool,
+        valid_result_count: i64,
+        invalid_result_count: i64,
+        consecutive_valid_result_count: i32,
+        total_credit: i64,
+        team_id: Option<Id<Team>>,
     }
 
     UserFilter {
@@ -25,6 +34,18 @@ record_impl! {
         name: Vec<String>,
         "$5::bool[] IS NULL OR array_position($5, is_admin) IS NOT NULL"
         is_admin: bool,
+        "$6::bool[] IS NULL OR array_position($6, beta_opt_in) IS NOT NULL"
+        beta_opt_in: Vec<bool>,
+        "$7::int8[] IS NULL OR array_position($7, valid_result_count) IS NOT NULL"
+        valid_result_count: Vec<i64>,
+        "$8::int8[] IS NULL OR array_position($8, invalid_result_count) IS NOT NULL"
+        invalid_result_count: Vec<i64>,
+        "$9::int4[] IS NULL OR array_position($9, consecutive_valid_result_count) IS NOT NULL"
+        consecutive_valid_result_count: Vec<i32>,
+        "$10::int8[] IS NULL OR array_position($10, total_credit) IS NOT NULL"
+        total_credit: Vec<i64>,
+        "$11::int8[] IS NULL OR array_position($11, team_id) IS NOT NULL"
+        team_id: Vec<Option<Id<Team>>>,
     }
 
     UserBuilder {
@@ -32,5 +53,10 @@ record_impl! {
         name: String,
     }
 
-    UpdateUser {}
+    UpdateUser {
+        update_disabled_at("disabled_at" Option<DateTime<Utc>>);
+        update_is_admin("is_admin" bool);
+        update_beta_opt_in("beta_opt_in" bool);
+        update_team_id("team_id" Option<Id<Team>>);
+    }
 }
@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    records::{Project, User, record_impl},
+    types::Id,
+};
+
+record_impl! {
+    PATH = "user_quotas";
+
+    UserQuota {
+        id: Id<UserQuota>,
+        updated_at: DateTime<Utc>,
+        user_id: Id<User>,
+        project_id: Id<Project>,
+        daily_quota: i32,
+    }
+
+    UserQuotaFilter {
+        "$1::int8[] IS NULL OR array_position($1, id) IS NOT NULL"
+        id: Vec<Id<UserQuota>>,
+        "$2::timestamptz[] IS NULL OR array_position($2, updated_at) IS NOT NULL"
+        updated_at: Vec<DateTime<Utc>>,
+        "$3::int8[] IS NULL OR array_position($3, user_id) IS NOT NULL"
+        user_id: Vec<Id<User>>,
+        "$4::int8[] IS NULL OR array_position($4, project_id) IS NOT NULL"
+        project_id: Vec<Id<Project>>,
+        "$5::int4[] IS NULL OR array_position($5, daily_quota) IS NOT NULL"
+        daily_quota: Vec<i32>,
+    }
+
+    UserQuotaBuilder {
+        "user_id" "$1"
+        user_id: Id<User>,
+        "project_id" "$2"
+        project_id: Id<Project>,
+        "daily_quota" "$3"
+        daily_quota: i32,
+    }
+
+    UpdateUserQuota {}
+}
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    records::{Project, record_impl},
+    types::{Id, WebhookEvent},
+};
+
+record_impl! {
+    PATH = "webhooks";
+
+    Webhook {
+        id: Id<Webhook>,
+        created_at: DateTime<Utc>,
+        disabled_at: Option<DateTime<Utc>>,
+        project_id: Id<Project>,
+        url: String,
+        events: Vec<WebhookEvent>,
+    }
+
+    WebhookFilter {
+        "$1::int8[] IS NULL OR array_position($1, id) IS NOT NULL"
+        id: Vec<Id<Webhook>>,
+        "$2::timestamptz[] IS NULL OR array_position($2, created_at) IS NOT NULL"
+        created_at: Vec<DateTime<Utc>>,
+        "$3::timestamptz[] IS NULL OR array_position($3, disabled_at) IS NOT NULL"
+        disabled_at: Vec<Option<DateTime<Utc>>>,
+        "$4::int8[] IS NULL OR array_position($4, project_id) IS NOT NULL"
+        project_id: Vec<Id<Project>>,
+        "$5::text[] IS NULL OR array_position($5, url) IS NOT NULL"
+        url: Vec<String>,
+    }
+
+    WebhookBuilder {
+        "project_id" "$1"
+        project_id: Id<Project>,
+        "url" "$2"
+        url: String,
+        "events" "$3"
+        events: Vec<WebhookEvent>,
+    }
+
+    UpdateWebhook {}
+}
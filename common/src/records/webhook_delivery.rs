@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    records::{Webhook, record_impl},
+    types::{Id, WebhookEvent},
+};
+
+record_impl! {
+    PATH = "webhook_deliveries";
+
+    WebhookDelivery {
+        id: Id<WebhookDelivery>,
+        created_at: DateTime<Utc>,
+        webhook_id: Id<Webhook>,
+        event: WebhookEvent,
+        payload: String,
+        attempts: i32,
+        next_attempt_at: DateTime<Utc>,
+        delivered_at: Option<DateTime<Utc>>,
+        last_status: Option<i32>,
+        last_error: Option<String>,
+    }
+
+    WebhookDeliveryFilter {
+        "$1::int8[] IS NULL OR array_position($1, id) IS NOT NULL"
+        id: Vec<Id<WebhookDelivery>>,
+        "$2::timestamptz[] IS NULL OR array_position($2, created_at) IS NOT NULL"
+        created_at: Vec<DateTime<Utc>>,
+        "$3::int8[] IS NULL OR array_position($3, webhook_id) IS NOT NULL"
+        webhook_id: Vec<Id<Webhook>>,
+        "$4::webhook_event[] IS NULL OR array_position($4, event) IS NOT NULL"
+        event: Vec<WebhookEvent>,
+        "$5::text[] IS NULL OR array_position($5, payload) IS NOT NULL"
+        payload: Vec<String>,
+        "$6::int4[] IS NULL OR array_position($6, attempts) IS NOT NULL"
+        attempts: Vec<i32>,
+        "$7::timestamptz[] IS NULL OR array_position($7, next_attempt_at) IS NOT NULL"
+        next_attempt_at: Vec<DateTime<Utc>>,
+        "$8::timestamptz[] IS NULL OR array_position($8, delivered_at) IS NOT NULL"
+        delivered_at: Vec<Option<DateTime<Utc>>>,
+        "$9::int4[] IS NULL OR array_position($9, last_status) IS NOT NULL"
+        last_status: Vec<Option<i32>>,
+        "$10::text[] IS NULL OR array_position($10, last_error) IS NOT NULL"
+        last_error: Vec<Option<String>>,
+    }
+
+    WebhookDeliveryBuilder {
+        "webhook_id" "$1"
+        webhook_id: Id<Webhook>,
+        "event" "$2"
+        event: WebhookEvent,
+        "payload" "$3"
+        payload: String,
+    }
+
+    UpdateWebhookDelivery {}
+}
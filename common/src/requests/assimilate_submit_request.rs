@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{records::Task, types::Id};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct AssimilateSubmitRequest {
+    pub task_ids: Vec<Id<Task>>,
+}
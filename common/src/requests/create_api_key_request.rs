@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::Scope;
+
+#[derive(Clone, Hash, Debug, Default, Serialize, Deserialize)]
+pub struct CreateApiKeyRequest {
+    // Revoke all other keys of the user, rotating to the new key.
+    pub revoke_others: bool,
+    // Defaults to the scopes of the key making the request.
+    pub scopes: Option<Vec<Scope>>,
+}
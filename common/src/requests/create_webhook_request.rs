@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    records::Project,
+    types::{Id, WebhookEvent},
+};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct CreateWebhookRequest {
+    pub project_id: Id<Project>,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+}
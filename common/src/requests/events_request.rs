@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    records::Project,
+    types::{EventType, Id},
+};
+
+#[derive(Clone, Hash, Debug, Default, Serialize, Deserialize)]
+pub struct EventsRequest {
+    // None to receive events of all projects.
+    pub project_ids: Option<Vec<Id<Project>>>,
+    // None to receive events of all types.
+    pub event_types: Option<Vec<EventType>>,
+}
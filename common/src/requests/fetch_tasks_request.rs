@@ -1,9 +1,15 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{records::Project, types::Id};
+use crate::{
+    records::Project,
+    types::{HostInfo, Id},
+};
 
 #[derive(Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct FetchTasksRequest {
     pub project_ids: Vec<Id<Project>>,
     pub limit: usize,
+    pub host: HostInfo,
+    // The etag of the last metadata received, if any.
+    pub metadata_etag: Option<String>,
 }
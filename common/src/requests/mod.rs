@@ -1,11 +1,39 @@
+pub mod assimilate_submit_request;
+pub mod create_api_key_request;
 pub mod create_file_request;
+pub mod create_team_request;
+pub mod create_webhook_request;
+pub mod events_request;
 pub mod fetch_tasks_request;
+pub mod kick_team_member_request;
 pub mod register_request;
+pub mod remove_project_member_request;
+pub mod set_beta_opt_in_request;
+pub mod set_project_member_request;
+pub mod set_task_priority_request;
+pub mod set_user_admin_request;
+pub mod set_user_disabled_request;
 pub mod submit_result_request;
+pub mod team_stats_request;
+pub mod user_stats_request;
 pub mod validate_submit_request;
 
+pub use assimilate_submit_request::AssimilateSubmitRequest;
+pub use create_api_key_request::CreateApiKeyRequest;
 pub use create_file_request::CreateFileRequest;
+pub use create_team_request::CreateTeamRequest;
+pub use create_webhook_request::CreateWebhookRequest;
+pub use events_request::EventsRequest;
 pub use fetch_tasks_request::FetchTasksRequest;
+pub use kick_team_member_request::KickTeamMemberRequest;
 pub use register_request::RegisterRequest;
+pub use remove_project_member_request::RemoveProjectMemberRequest;
+pub use set_beta_opt_in_request::SetBetaOptInRequest;
+pub use set_project_member_request::SetProjectMemberRequest;
+pub use set_task_priority_request::SetTaskPriorityRequest;
+pub use set_user_admin_request::SetUserAdminRequest;
+pub use set_user_disabled_request::SetUserDisabledRequest;
 pub use submit_result_request::SubmitResultRequest;
+pub use team_stats_request::TeamStatsRequest;
+pub use user_stats_request::UserStatsRequest;
 pub use validate_submit_request::ValidateSubmitRequest;
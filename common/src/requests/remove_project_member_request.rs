@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{records::User, types::Id};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct RemoveProjectMemberRequest {
+    pub user_id: Id<User>,
+}
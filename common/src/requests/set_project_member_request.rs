@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    records::User,
+    types::{Id, ProjectRole},
+};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct SetProjectMemberRequest {
+    pub user_id: Id<User>,
+    pub role: ProjectRole,
+}
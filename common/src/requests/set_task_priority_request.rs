@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::records::TaskFilter;
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct SetTaskPriorityRequest {
+    pub filter: TaskFilter,
+    pub priority: i32,
+}
@@ -0,0 +1,6 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct SetUserAdminRequest {
+    pub is_admin: bool,
+}
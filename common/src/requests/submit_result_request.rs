@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
 
+use crate::types::Interval;
+
 #[derive(Clone, Hash, Debug, Serialize, Deserialize)]
 pub struct SubmitResultRequest {
     pub stdout: String,
     pub stderr: String,
     pub exit_code: Option<i32>,
+    // None if the client could not measure it.
+    pub cpu_time: Option<Interval>,
+    pub wall_time: Option<Interval>,
 }
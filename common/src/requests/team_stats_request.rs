@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Hash, Debug, Default, Serialize, Deserialize)]
+pub struct TeamStatsRequest {
+    pub offset: Option<i64>,
+    // At most 1000, defaults to 100.
+    pub limit: Option<i64>,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::records::{Result, Task};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct AssimilateFetchResponse {
+    pub tasks: Vec<Task>,
+    // The canonical result of each task in `tasks`.
+    pub canonical_results: Vec<Result>,
+}
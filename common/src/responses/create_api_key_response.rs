@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{records::ApiKey, types::Id};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct CreateApiKeyResponse {
+    pub api_key_id: Id<ApiKey>,
+    pub api_key: String,
+}
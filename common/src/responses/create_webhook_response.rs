@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{records::Webhook, types::Id};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct CreateWebhookResponse {
+    pub webhook_id: Id<Webhook>,
+    // Key for the HMAC-SHA256 signature sent with each delivery.
+    pub secret: String,
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    records::{File, Project, ProjectVersion, Task},
+    types::Id,
+};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct FetchTasksResponse {
+    pub tasks: Vec<Task>,
+    // The project version to run for each task in `tasks`, in the same order.
+    pub project_version_ids: Vec<Id<ProjectVersion>>,
+    pub metadata_etag: String,
+    // None if the metadata matches the etag from the request.
+    pub metadata: Option<FetchTasksMetadata>,
+}
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct FetchTasksMetadata {
+    pub projects: Vec<Project>,
+    // The project versions the tasks can be served, the preferred one for each project in
+    // `projects` and one for each other platform class for projects with homogeneous redundancy.
+    pub project_versions: Vec<ProjectVersion>,
+    pub files: Vec<File>,
+}
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct GlobalStatsResponse {
+    pub user_count: i64,
+    // Users with hosts that fetched tasks over the last day.
+    pub active_user_count: i64,
+    pub active_host_count: i64,
+    pub project_count: i64,
+    pub task_count: i64,
+    pub remaining_task_count: i64,
+    pub in_progress_assignment_count: i64,
+    pub total_credit: i64,
+    pub refreshed_at: Option<DateTime<Utc>>,
+}
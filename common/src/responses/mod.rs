@@ -1,3 +1,23 @@
+pub mod assimilate_fetch_response;
+pub mod create_api_key_response;
+pub mod create_webhook_response;
+pub mod fetch_tasks_response;
+pub mod global_stats_response;
+pub mod project_stats_response;
+pub mod readyz_response;
 pub mod register_response;
+pub mod team_stats_response;
+pub mod user_activity_response;
+pub mod user_stats_response;
 
+pub use assimilate_fetch_response::AssimilateFetchResponse;
+pub use create_api_key_response::CreateApiKeyResponse;
+pub use create_webhook_response::CreateWebhookResponse;
+pub use fetch_tasks_response::{FetchTasksMetadata, FetchTasksResponse};
+pub use global_stats_response::GlobalStatsResponse;
+pub use project_stats_response::ProjectStatsResponse;
+pub use readyz_response::{ReadinessCheck, ReadyzResponse};
 pub use register_response::RegisterResponse;
+pub use team_stats_response::TeamStatsResponse;
+pub use user_activity_response::UserActivityResponse;
+pub use user_stats_response::UserStatsResponse;
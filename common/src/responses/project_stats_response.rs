@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{records::Project, types::Id};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct ProjectStatsResponse {
+    pub project_id: Id<Project>,
+    pub name: String,
+    pub task_count: i64,
+    // Tasks that are neither valid nor failed.
+    pub remaining_task_count: i64,
+    pub valid_task_count: i64,
+    pub failed_task_count: i64,
+    pub in_progress_assignment_count: i64,
+    // Users that fetched tasks over the last day.
+    pub active_user_count: i64,
+    pub total_credit: i64,
+    pub refreshed_at: DateTime<Utc>,
+}
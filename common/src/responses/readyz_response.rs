@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct ReadyzResponse {
+    // Whether all critical checks passed, in which case the status is 200 rather than 503.
+    pub ready: bool,
+    pub checks: Vec<ReadinessCheck>,
+}
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct ReadinessCheck {
+    pub name: String,
+    pub ok: bool,
+    // Failed checks that are not critical are only reported.
+    pub critical: bool,
+    // Why the check failed, or what it found.
+    pub message: Option<String>,
+}
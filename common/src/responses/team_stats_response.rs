@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{records::Team, types::Id};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct TeamStatsResponse {
+    pub team_id: Id<Team>,
+    pub name: String,
+    pub member_count: i64,
+    // Credit of the current members, including credit granted before they joined.
+    pub total_credit: i64,
+    // Credit granted to the current members over the last 7 days.
+    pub recent_credit: i64,
+    pub valid_result_count: i64,
+    pub rank: i64,
+    pub refreshed_at: DateTime<Utc>,
+}
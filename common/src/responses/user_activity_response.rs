@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::records::{Assignment, Result};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct UserActivityResponse {
+    // The most recent assignments and results of the user, newest first.
+    pub assignments: Vec<Assignment>,
+    pub results: Vec<Result>,
+}
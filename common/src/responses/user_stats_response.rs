@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{records::User, types::Id};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct UserStatsResponse {
+    pub user_id: Id<User>,
+    pub name: String,
+    pub total_credit: i64,
+    // Credit granted over the last 7 days.
+    pub recent_credit: i64,
+    pub valid_result_count: i64,
+    pub invalid_result_count: i64,
+    // Hosts that fetched tasks over the last day.
+    pub active_host_count: i64,
+    pub rank: i64,
+    pub refreshed_at: DateTime<Utc>,
+}
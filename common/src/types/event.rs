@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{records::Project, types::Id};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct Event {
+    pub event_type: EventType,
+    // The id of the task, assignment or result, depending on `event_type`.
+    pub id: i64,
+    pub project_id: Id<Project>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
+pub enum EventType {
+    TaskCreated,
+    TaskUpdated,
+    AssignmentCreated,
+    AssignmentUpdated,
+    ResultCreated,
+    ResultUpdated,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{records::Platform, types::Id};
+
+#[derive(Clone, Hash, Debug, Serialize, Deserialize)]
+pub struct HostInfo {
+    pub name: String,
+    pub cpu_model: String,
+    pub core_count: i32,
+    pub memory: i64,
+    pub os: String,
+    pub platform_ids: Vec<Id<Platform>>,
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(
@@ -9,6 +11,23 @@ pub struct Interval {
     pub microseconds: i64,
 }
 
+impl Interval {
+    /// Whether any part of the interval is negative. Clients never measure negative times.
+    pub fn is_negative(&self) -> bool {
+        self.months < 0 || self.days < 0 || self.microseconds < 0
+    }
+}
+
+impl From<Duration> for Interval {
+    fn from(duration: Duration) -> Self {
+        Self {
+            months: 0,
+            days: 0,
+            microseconds: duration.as_micros() as i64,
+        }
+    }
+}
+
 #[cfg(feature = "sqlx")]
 mod sqlx {
     use sqlx::{
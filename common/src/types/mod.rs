@@ -1,9 +1,19 @@
 pub mod assignment_state;
+pub mod event;
+pub mod host_info;
 pub mod id;
 pub mod interval;
+pub mod project_role;
 pub mod result_state;
+pub mod scope;
+pub mod webhook_event;
 
 pub use assignment_state::AssignmentState;
+pub use event::{Event, EventType};
+pub use host_info::HostInfo;
 pub use id::Id;
 pub use interval::Interval;
+pub use project_role::ProjectRole;
 pub use result_state::ResultState;
+pub use scope::{ParseScopeError, Scope};
+pub use webhook_event::WebhookEvent;
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// The role of a project member. Every role can do everything the roles before it can.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "sqlx",
+    sqlx(type_name = "project_role", rename_all = "snake_case")
+)]
+pub enum ProjectRole {
+    Viewer,
+    Validator,
+    Maintainer,
+    Owner,
+}
@@ -0,0 +1,126 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{records::Project, types::Id};
+
+/// A permission an API key can be restricted to, written as e.g. `tasks:fetch` or
+/// `project:1:validate`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum Scope {
+    TasksFetch,
+    ResultsSubmit,
+    FilesCreate,
+    TeamsManage,
+    AccountManage,
+    UsersManage,
+    EventsRead,
+    ProjectValidate(Id<Project>),
+    ProjectAssimilate(Id<Project>),
+    ProjectManage(Id<Project>),
+}
+
+#[derive(Clone, Hash, Debug, Error)]
+#[error("invalid scope: {0}")]
+pub struct ParseScopeError(String);
+
+impl Display for Scope {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::TasksFetch => write!(f, "tasks:fetch"),
+            Self::ResultsSubmit => write!(f, "results:submit"),
+            Self::FilesCreate => write!(f, "files:create"),
+            Self::TeamsManage => write!(f, "teams:manage"),
+            Self::AccountManage => write!(f, "account:manage"),
+            Self::UsersManage => write!(f, "users:manage"),
+            Self::EventsRead => write!(f, "events:read"),
+            Self::ProjectValidate(project_id) => write!(f, "project:{project_id}:validate"),
+            Self::ProjectAssimilate(project_id) => write!(f, "project:{project_id}:assimilate"),
+            Self::ProjectManage(project_id) => write!(f, "project:{project_id}:manage"),
+        }
+    }
+}
+
+impl FromStr for Scope {
+    type Err = ParseScopeError;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let err = || ParseScopeError(src.to_string());
+
+        Ok(match src.split(':').collect::<Vec<_>>()[..] {
+            ["tasks", "fetch"] => Self::TasksFetch,
+            ["results", "submit"] => Self::ResultsSubmit,
+            ["files", "create"] => Self::FilesCreate,
+            ["teams", "manage"] => Self::TeamsManage,
+            ["account", "manage"] => Self::AccountManage,
+            ["users", "manage"] => Self::UsersManage,
+            ["events", "read"] => Self::EventsRead,
+            ["project", project_id, action] => {
+                let project_id = project_id.parse().map_err(|_| err())?;
+
+                match action {
+                    "validate" => Self::ProjectValidate(project_id),
+                    "assimilate" => Self::ProjectAssimilate(project_id),
+                    "manage" => Self::ProjectManage(project_id),
+                    _ => Err(err())?,
+                }
+            }
+            _ => Err(err())?,
+        })
+    }
+}
+
+impl From<Scope> for String {
+    fn from(scope: Scope) -> Self {
+        scope.to_string()
+    }
+}
+
+impl TryFrom<String> for Scope {
+    type Error = ParseScopeError;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx {
+    use sqlx::{
+        Decode, Encode, Postgres, Type,
+        encode::IsNull,
+        error::BoxDynError,
+        postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef},
+    };
+
+    use super::Scope;
+
+    impl Type<Postgres> for Scope {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+    }
+
+    impl Encode<'_, Postgres> for Scope {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            <String as Encode<Postgres>>::encode_by_ref(&self.to_string(), buf)
+        }
+    }
+
+    impl Decode<'_, Postgres> for Scope {
+        fn decode(value: PgValueRef) -> Result<Self, BoxDynError> {
+            Ok(<&str as Decode<Postgres>>::decode(value)?.parse()?)
+        }
+    }
+
+    impl PgHasArrayType for Scope {
+        fn array_type_info() -> PgTypeInfo {
+            String::array_type_info()
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "sqlx",
+    sqlx(type_name = "webhook_event", rename_all = "snake_case")
+)]
+pub enum WebhookEvent {
+    TaskValid,
+    TaskFailed,
+    QueueDrained,
+}
@@ -0,0 +1,23 @@
+CREATE TABLE hosts (
+    id int8 GENERATED ALWAYS AS IDENTITY NOT NULL PRIMARY KEY,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz NOT NULL DEFAULT now(),
+    user_id int8 NOT NULL REFERENCES users(id) ON DELETE RESTRICT ON UPDATE RESTRICT,
+    name text NOT NULL,
+    cpu_model text NOT NULL,
+    core_count int4 NOT NULL,
+    memory int8 NOT NULL,
+    os text NOT NULL,
+    platform_ids int8[] NOT NULL DEFAULT ARRAY[]::int8[]
+);
+
+CREATE UNIQUE INDEX hosts_user_id_name_key
+ON hosts (user_id, name);
+
+ALTER TABLE assignments
+ADD COLUMN host_id int8 REFERENCES hosts(id) ON DELETE RESTRICT ON UPDATE RESTRICT;
+
+ALTER TABLE projects
+ADD COLUMN min_core_count int4,
+ADD COLUMN min_memory int8,
+ADD COLUMN required_platform_id int8 REFERENCES platforms(id) ON DELETE RESTRICT ON UPDATE RESTRICT;
@@ -0,0 +1,2 @@
+ALTER TABLE assignments
+ADD COLUMN project_version_id int8 REFERENCES project_versions(id) ON DELETE RESTRICT ON UPDATE RESTRICT;
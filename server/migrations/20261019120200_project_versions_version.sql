@@ -0,0 +1,27 @@
+ALTER TABLE project_versions
+ADD COLUMN version int4,
+ADD COLUMN is_beta boolean NOT NULL DEFAULT false;
+
+-- number existing versions in creation order
+UPDATE
+    project_versions pv
+SET
+    version = numbered.version
+FROM (
+    SELECT
+        id,
+        row_number() OVER (PARTITION BY project_id, platform_id ORDER BY id) version
+    FROM
+        project_versions
+) numbered
+WHERE
+    pv.id = numbered.id;
+
+ALTER TABLE project_versions
+ALTER COLUMN version SET NOT NULL;
+
+CREATE UNIQUE INDEX project_versions_project_id_platform_id_version_key
+ON project_versions (project_id, platform_id, version);
+
+ALTER TABLE users
+ADD COLUMN beta_opt_in boolean NOT NULL DEFAULT false;
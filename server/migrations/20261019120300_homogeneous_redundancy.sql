@@ -0,0 +1,12 @@
+-- platforms in the same class produce bit-identical results, NULL means the class is the platform
+-- name
+ALTER TABLE platforms
+ADD COLUMN hr_class text;
+
+ALTER TABLE projects
+ADD COLUMN homogeneous_redundancy boolean NOT NULL DEFAULT false;
+
+ALTER TABLE tasks
+ADD COLUMN hr_class text,
+ADD COLUMN canonical_result_id int8 REFERENCES results(id) ON DELETE SET NULL ON UPDATE CASCADE,
+ADD COLUMN canonical_hr_class text;
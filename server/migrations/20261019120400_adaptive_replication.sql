@@ -0,0 +1,9 @@
+ALTER TABLE users
+ADD COLUMN valid_result_count int8 NOT NULL DEFAULT 0,
+ADD COLUMN invalid_result_count int8 NOT NULL DEFAULT 0,
+ADD COLUMN consecutive_valid_result_count int4 NOT NULL DEFAULT 0;
+
+-- NULL disables adaptive replication
+ALTER TABLE projects
+ADD COLUMN adaptive_replication_threshold int4,
+ADD COLUMN adaptive_replication_spot_check_percent int4 NOT NULL DEFAULT 10;
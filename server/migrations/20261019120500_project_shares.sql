@@ -0,0 +1,8 @@
+ALTER TABLE projects
+ADD COLUMN share int4 NOT NULL DEFAULT 100 CHECK (share > 0);
+
+CREATE INDEX assignments_created_at_idx
+ON assignments (created_at);
+
+CREATE INDEX tasks_project_id_retry_id_idx
+ON tasks (project_id, (assignments_needed > quorum) DESC, id);
@@ -0,0 +1,7 @@
+ALTER TABLE tasks
+ADD COLUMN priority int4 NOT NULL DEFAULT 0;
+
+DROP INDEX tasks_project_id_retry_id_idx;
+
+CREATE INDEX tasks_project_id_priority_retry_id_idx
+ON tasks (project_id, priority DESC, (assignments_needed > quorum) DESC, id);
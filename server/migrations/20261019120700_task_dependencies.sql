@@ -0,0 +1,11 @@
+ALTER TABLE tasks
+ADD COLUMN dependency_task_ids int8[] NOT NULL DEFAULT '{}',
+ADD COLUMN follow_up_created_at timestamptz;
+
+ALTER TABLE projects
+ADD COLUMN follow_up_project_id int8 REFERENCES projects(id) ON DELETE RESTRICT ON UPDATE RESTRICT,
+ADD COLUMN follow_up_program text;
+
+CREATE INDEX tasks_follow_up_idx
+ON tasks (project_id)
+WHERE canonical_result_id IS NOT NULL AND follow_up_created_at IS NULL;
@@ -0,0 +1,6 @@
+ALTER TABLE tasks
+ADD COLUMN assimilated_at timestamptz;
+
+CREATE INDEX tasks_assimilate_idx
+ON tasks (project_id, id)
+WHERE canonical_result_id IS NOT NULL AND assimilated_at IS NULL;
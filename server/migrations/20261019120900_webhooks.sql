@@ -0,0 +1,44 @@
+CREATE TYPE webhook_event AS ENUM (
+    'task_valid',
+    'task_failed',
+    'queue_drained'
+);
+
+CREATE TABLE webhooks (
+    id int8 GENERATED ALWAYS AS IDENTITY NOT NULL PRIMARY KEY,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    disabled_at timestamptz,
+    project_id int8 NOT NULL REFERENCES projects(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    url text NOT NULL,
+    events webhook_event[] NOT NULL
+);
+
+CREATE INDEX webhooks_project_id_idx
+ON webhooks (project_id);
+
+CREATE TABLE webhook_deliveries (
+    id int8 GENERATED ALWAYS AS IDENTITY NOT NULL PRIMARY KEY,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    webhook_id int8 NOT NULL REFERENCES webhooks(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    event webhook_event NOT NULL,
+    payload text NOT NULL,
+    attempts int4 NOT NULL DEFAULT 0,
+    next_attempt_at timestamptz NOT NULL DEFAULT now(),
+    delivered_at timestamptz,
+    last_status int4,
+    last_error text
+);
+
+CREATE INDEX webhook_deliveries_pending_idx
+ON webhook_deliveries (next_attempt_at)
+WHERE delivered_at IS NULL;
+
+ALTER TABLE projects
+ADD COLUMN max_error_results int4;
+
+ALTER TABLE tasks
+ADD COLUMN failed_at timestamptz;
+
+CREATE INDEX tasks_project_id_open_idx
+ON tasks (project_id)
+WHERE canonical_result_id IS NULL AND failed_at IS NULL;
@@ -0,0 +1,80 @@
+-- notify listeners of changes to tasks, assignments and results
+CREATE FUNCTION notify_record_change(event_type text, id int8, project_id int8)
+RETURNS void
+LANGUAGE plpgsql
+AS $$ BEGIN
+    PERFORM pg_notify(
+        'record_changes',
+        json_build_object(
+            'event_type', event_type,
+            'id', id,
+            'project_id', project_id
+        )::text
+    );
+END $$;
+
+CREATE FUNCTION notify_tasks_change()
+RETURNS TRIGGER
+LANGUAGE plpgsql
+AS $$ BEGIN
+    PERFORM notify_record_change(
+        CASE TG_OP WHEN 'INSERT' THEN 'TaskCreated' ELSE 'TaskUpdated' END,
+        NEW.id,
+        NEW.project_id
+    );
+
+    RETURN NULL;
+END $$;
+
+CREATE FUNCTION notify_assignments_change()
+RETURNS TRIGGER
+LANGUAGE plpgsql
+AS $$ BEGIN
+    PERFORM notify_record_change(
+        CASE TG_OP WHEN 'INSERT' THEN 'AssignmentCreated' ELSE 'AssignmentUpdated' END,
+        NEW.id,
+        (SELECT t.project_id FROM tasks t WHERE t.id = NEW.task_id)
+    );
+
+    RETURN NULL;
+END $$;
+
+CREATE FUNCTION notify_results_change()
+RETURNS TRIGGER
+LANGUAGE plpgsql
+AS $$ BEGIN
+    PERFORM notify_record_change(
+        CASE TG_OP WHEN 'INSERT' THEN 'ResultCreated' ELSE 'ResultUpdated' END,
+        NEW.id,
+        (
+            SELECT
+                t.project_id
+            FROM
+                assignments a,
+                tasks t
+            WHERE
+                a.id = NEW.assignment_id
+                AND t.id = a.task_id
+        )
+    );
+
+    RETURN NULL;
+END $$;
+
+CREATE TRIGGER notify_tasks_change_after_insert_or_update
+AFTER INSERT OR UPDATE
+ON tasks
+FOR EACH ROW
+EXECUTE FUNCTION notify_tasks_change();
+
+CREATE TRIGGER notify_assignments_change_after_insert_or_update
+AFTER INSERT OR UPDATE
+ON assignments
+FOR EACH ROW
+EXECUTE FUNCTION notify_assignments_change();
+
+CREATE TRIGGER notify_results_change_after_insert_or_update
+AFTER INSERT OR UPDATE
+ON results
+FOR EACH ROW
+EXECUTE FUNCTION notify_results_change();
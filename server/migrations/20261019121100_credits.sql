@@ -0,0 +1,54 @@
+ALTER TABLE results
+ADD COLUMN cpu_time interval,
+ADD COLUMN wall_time interval;
+
+ALTER TABLE projects
+ADD COLUMN credit_per_result int8 NOT NULL DEFAULT 0,
+ADD COLUMN credit_per_cpu_hour int8 NOT NULL DEFAULT 0,
+ADD COLUMN total_credit int8 NOT NULL DEFAULT 0;
+
+ALTER TABLE users
+ADD COLUMN total_credit int8 NOT NULL DEFAULT 0;
+
+CREATE TABLE credits (
+    id int8 GENERATED ALWAYS AS IDENTITY NOT NULL PRIMARY KEY,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    user_id int8 NOT NULL REFERENCES users(id) ON DELETE RESTRICT ON UPDATE RESTRICT,
+    project_id int8 NOT NULL REFERENCES projects(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    result_id int8 NOT NULL UNIQUE REFERENCES results(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    amount int8 NOT NULL
+);
+
+CREATE INDEX credits_user_id_idx
+ON credits (user_id);
+
+CREATE INDEX credits_project_id_idx
+ON credits (project_id);
+
+-- update users.total_credit and projects.total_credit
+CREATE FUNCTION add_total_credit()
+RETURNS TRIGGER
+LANGUAGE plpgsql
+AS $$ BEGIN
+    UPDATE
+        users
+    SET
+        total_credit = total_credit + NEW.amount
+    WHERE
+        id = NEW.user_id;
+
+    UPDATE
+        projects
+    SET
+        total_credit = total_credit + NEW.amount
+    WHERE
+        id = NEW.project_id;
+
+    RETURN NEW;
+END $$;
+
+CREATE TRIGGER add_total_credit_after_insert
+AFTER INSERT
+ON credits
+FOR EACH ROW
+EXECUTE FUNCTION add_total_credit();
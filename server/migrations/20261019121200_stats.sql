@@ -0,0 +1,88 @@
+CREATE MATERIALIZED VIEW user_stats AS
+SELECT
+    u.id AS user_id,
+    u.name,
+    u.total_credit,
+    coalesce(c.recent_credit, 0)::int8 AS recent_credit,
+    u.valid_result_count,
+    u.invalid_result_count,
+    coalesce(h.active_host_count, 0) AS active_host_count,
+    rank() OVER (ORDER BY u.total_credit DESC) AS rank,
+    now() AS refreshed_at
+FROM
+    users u
+    LEFT JOIN (
+        SELECT
+            user_id,
+            sum(amount) AS recent_credit
+        FROM
+            credits
+        WHERE
+            created_at > now() - interval '7 days'
+        GROUP BY
+            user_id
+    ) c ON
+        c.user_id = u.id
+    LEFT JOIN (
+        SELECT
+            user_id,
+            count(*) AS active_host_count
+        FROM
+            hosts
+        WHERE
+            updated_at > now() - interval '1 day'
+        GROUP BY
+            user_id
+    ) h ON
+        h.user_id = u.id
+WHERE
+    u.disabled_at IS NULL;
+
+CREATE UNIQUE INDEX user_stats_user_id_key
+ON user_stats (user_id);
+
+CREATE INDEX user_stats_rank_idx
+ON user_stats (rank);
+
+CREATE MATERIALIZED VIEW project_stats AS
+SELECT
+    p.id AS project_id,
+    p.name,
+    count(t.id) AS task_count,
+    count(t.id) FILTER (
+        WHERE t.canonical_result_id IS NULL AND t.failed_at IS NULL
+    ) AS remaining_task_count,
+    count(t.id) FILTER (WHERE t.canonical_result_id IS NOT NULL) AS valid_task_count,
+    count(t.id) FILTER (WHERE t.failed_at IS NOT NULL) AS failed_task_count,
+    coalesce(a.in_progress_assignment_count, 0) AS in_progress_assignment_count,
+    coalesce(a.active_user_count, 0) AS active_user_count,
+    p.total_credit,
+    now() AS refreshed_at
+FROM
+    projects p
+    LEFT JOIN tasks t ON
+        t.project_id = p.id
+    LEFT JOIN (
+        SELECT
+            t.project_id,
+            count(*) FILTER (WHERE a.state = 'init') AS in_progress_assignment_count,
+            count(DISTINCT a.user_id) FILTER (
+                WHERE a.created_at > now() - interval '1 day'
+            ) AS active_user_count
+        FROM
+            assignments a,
+            tasks t
+        WHERE
+            (a.state = 'init' OR a.created_at > now() - interval '1 day')
+            AND t.id = a.task_id
+        GROUP BY
+            t.project_id
+    ) a ON
+        a.project_id = p.id
+GROUP BY
+    p.id,
+    a.in_progress_assignment_count,
+    a.active_user_count;
+
+CREATE UNIQUE INDEX project_stats_project_id_key
+ON project_stats (project_id);
@@ -0,0 +1,41 @@
+CREATE TABLE teams (
+    id int8 GENERATED ALWAYS AS IDENTITY NOT NULL PRIMARY KEY,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    founder_user_id int8 NOT NULL REFERENCES users(id) ON DELETE RESTRICT ON UPDATE RESTRICT,
+    name text NOT NULL
+);
+
+CREATE UNIQUE INDEX teams_name_key
+ON teams (lower(name));
+
+ALTER TABLE users
+ADD COLUMN team_id int8 REFERENCES teams(id) ON DELETE SET NULL ON UPDATE CASCADE;
+
+CREATE INDEX users_team_id_idx
+ON users (team_id);
+
+CREATE MATERIALIZED VIEW team_stats AS
+SELECT
+    t.id AS team_id,
+    t.name,
+    count(u.id) AS member_count,
+    coalesce(sum(u.total_credit), 0)::int8 AS total_credit,
+    coalesce(sum(us.recent_credit), 0)::int8 AS recent_credit,
+    coalesce(sum(u.valid_result_count), 0)::int8 AS valid_result_count,
+    rank() OVER (ORDER BY coalesce(sum(u.total_credit), 0) DESC) AS rank,
+    now() AS refreshed_at
+FROM
+    teams t
+    LEFT JOIN users u ON
+        u.team_id = t.id
+        AND u.disabled_at IS NULL
+    LEFT JOIN user_stats us ON
+        us.user_id = u.id
+GROUP BY
+    t.id;
+
+CREATE UNIQUE INDEX team_stats_team_id_key
+ON team_stats (team_id);
+
+CREATE INDEX team_stats_rank_idx
+ON team_stats (rank);
@@ -0,0 +1,18 @@
+CREATE TABLE api_keys (
+    id int8 GENERATED ALWAYS AS IDENTITY NOT NULL PRIMARY KEY,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    revoked_at timestamptz,
+    user_id int8 NOT NULL REFERENCES users(id) ON DELETE RESTRICT ON UPDATE RESTRICT
+);
+
+CREATE INDEX api_keys_user_id_idx
+ON api_keys (user_id);
+
+-- Keys used to be derived from the user id. Giving every existing user a key with the same id
+-- keeps those keys valid.
+INSERT INTO api_keys (id, created_at, user_id)
+OVERRIDING SYSTEM VALUE
+SELECT id, created_at, id FROM users;
+
+SELECT setval(pg_get_serial_sequence('api_keys', 'id'), coalesce(max(id), 0) + 1, false)
+FROM api_keys;
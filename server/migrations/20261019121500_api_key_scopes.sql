@@ -0,0 +1,3 @@
+-- NULL means the key is not restricted and can do everything its user can.
+ALTER TABLE api_keys
+ADD COLUMN scopes text[];
@@ -0,0 +1,54 @@
+CREATE TYPE project_role AS ENUM (
+    'viewer',
+    'validator',
+    'maintainer',
+    'owner'
+);
+
+CREATE TABLE project_members (
+    id int8 GENERATED ALWAYS AS IDENTITY NOT NULL PRIMARY KEY,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    project_id int8 NOT NULL REFERENCES projects(id) ON DELETE CASCADE ON UPDATE RESTRICT,
+    user_id int8 NOT NULL REFERENCES users(id) ON DELETE CASCADE ON UPDATE RESTRICT,
+    role project_role NOT NULL,
+    UNIQUE (project_id, user_id)
+);
+
+CREATE INDEX project_members_user_id_idx
+ON project_members (user_id);
+
+INSERT INTO project_members (
+    project_id,
+    user_id,
+    role
+)
+SELECT
+    id,
+    created_by_user_id,
+    'owner'
+FROM
+    projects;
+
+-- the creator of a project becomes its first owner
+CREATE FUNCTION add_project_members_owner()
+RETURNS TRIGGER
+LANGUAGE plpgsql
+AS $$ BEGIN
+    INSERT INTO project_members (
+        project_id,
+        user_id,
+        role
+    ) VALUES (
+        NEW.id,
+        NEW.created_by_user_id,
+        'owner'
+    );
+
+    RETURN NEW;
+END $$;
+
+CREATE TRIGGER add_project_members_owner_after_insert
+AFTER INSERT
+ON projects
+FOR EACH ROW
+EXECUTE FUNCTION add_project_members_owner();
@@ -0,0 +1,62 @@
+-- NULL means unlimited.
+ALTER TABLE projects
+ADD COLUMN max_in_progress_per_user int4,
+ADD COLUMN daily_quota int4;
+
+-- The daily quota of a user in a project. Users without a row have the full quota of the project.
+CREATE TABLE user_quotas (
+    id int8 GENERATED ALWAYS AS IDENTITY NOT NULL PRIMARY KEY,
+    updated_at timestamptz NOT NULL DEFAULT now(),
+    user_id int8 NOT NULL REFERENCES users(id) ON DELETE CASCADE ON UPDATE RESTRICT,
+    project_id int8 NOT NULL REFERENCES projects(id) ON DELETE CASCADE ON UPDATE RESTRICT,
+    daily_quota int4 NOT NULL,
+    UNIQUE (user_id, project_id)
+);
+
+CREATE INDEX assignments_user_id_state_idx
+ON assignments (user_id, state);
+
+-- update user_quotas.daily_quota
+-- A valid result doubles the quota up to the quota of the project, an invalid or error result
+-- halves it, but never below one.
+CREATE FUNCTION update_user_quotas_daily_quota()
+RETURNS TRIGGER
+LANGUAGE plpgsql
+AS $$ BEGIN
+    INSERT INTO user_quotas (
+        user_id,
+        project_id,
+        daily_quota
+    )
+    SELECT
+        a.user_id,
+        p.id,
+        CASE
+            WHEN NEW.state = 'valid' THEN p.daily_quota
+            ELSE greatest(p.daily_quota / 2, 1)
+        END
+    FROM
+        assignments a,
+        tasks t,
+        projects p
+    WHERE
+        a.id = NEW.assignment_id
+        AND t.id = a.task_id
+        AND p.id = t.project_id
+        AND p.daily_quota IS NOT NULL
+    ON CONFLICT (user_id, project_id) DO UPDATE SET
+        updated_at = now(),
+        daily_quota = CASE
+            WHEN NEW.state = 'valid' THEN least(user_quotas.daily_quota * 2, EXCLUDED.daily_quota)
+            ELSE greatest(user_quotas.daily_quota / 2, 1)
+        END;
+
+    RETURN NEW;
+END $$;
+
+CREATE TRIGGER update_user_quotas_daily_quota_after_update
+AFTER UPDATE OF state
+ON results
+FOR EACH ROW
+WHEN (OLD.state IS DISTINCT FROM NEW.state AND NEW.state IN ('valid', 'invalid', 'error'))
+EXECUTE FUNCTION update_user_quotas_daily_quota();
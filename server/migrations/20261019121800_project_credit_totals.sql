@@ -0,0 +1,75 @@
+-- Credit totals of projects are summed when the statistics are refreshed. Keeping them in
+-- projects made every validation lock the project row, and changed the task metadata that
+-- clients cache on every credited result.
+CREATE OR REPLACE FUNCTION add_total_credit()
+RETURNS TRIGGER
+LANGUAGE plpgsql
+AS $$ BEGIN
+    UPDATE
+        users
+    SET
+        total_credit = total_credit + NEW.amount
+    WHERE
+        id = NEW.user_id;
+
+    RETURN NEW;
+END $$;
+
+DROP MATERIALIZED VIEW project_stats;
+
+ALTER TABLE projects
+DROP COLUMN total_credit;
+
+CREATE MATERIALIZED VIEW project_stats AS
+SELECT
+    p.id AS project_id,
+    p.name,
+    count(t.id) AS task_count,
+    count(t.id) FILTER (
+        WHERE t.canonical_result_id IS NULL AND t.failed_at IS NULL
+    ) AS remaining_task_count,
+    count(t.id) FILTER (WHERE t.canonical_result_id IS NOT NULL) AS valid_task_count,
+    count(t.id) FILTER (WHERE t.failed_at IS NOT NULL) AS failed_task_count,
+    coalesce(a.in_progress_assignment_count, 0) AS in_progress_assignment_count,
+    coalesce(a.active_user_count, 0) AS active_user_count,
+    coalesce(c.total_credit, 0)::int8 AS total_credit,
+    now() AS refreshed_at
+FROM
+    projects p
+    LEFT JOIN tasks t ON
+        t.project_id = p.id
+    LEFT JOIN (
+        SELECT
+            t.project_id,
+            count(*) FILTER (WHERE a.state = 'init') AS in_progress_assignment_count,
+            count(DISTINCT a.user_id) FILTER (
+                WHERE a.created_at > now() - interval '1 day'
+            ) AS active_user_count
+        FROM
+            assignments a,
+            tasks t
+        WHERE
+            (a.state = 'init' OR a.created_at > now() - interval '1 day')
+            AND t.id = a.task_id
+        GROUP BY
+            t.project_id
+    ) a ON
+        a.project_id = p.id
+    LEFT JOIN (
+        SELECT
+            project_id,
+            sum(amount) AS total_credit
+        FROM
+            credits
+        GROUP BY
+            project_id
+    ) c ON
+        c.project_id = p.id
+GROUP BY
+    p.id,
+    a.in_progress_assignment_count,
+    a.active_user_count,
+    c.total_credit;
+
+CREATE UNIQUE INDEX project_stats_project_id_key
+ON project_stats (project_id);
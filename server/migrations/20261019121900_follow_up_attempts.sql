@@ -0,0 +1,5 @@
+-- Follow-up programs run outside of a transaction, so a task is claimed by counting an attempt.
+-- Tasks whose program keeps failing are given up on, rather than being retried forever.
+ALTER TABLE tasks
+ADD COLUMN follow_up_attempt_count int4 NOT NULL DEFAULT 0,
+ADD COLUMN follow_up_attempted_at timestamptz;
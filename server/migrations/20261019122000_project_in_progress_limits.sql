@@ -0,0 +1,11 @@
+-- The number of assignments of a project in progress across all users. NULL means unlimited.
+ALTER TABLE projects
+ADD COLUMN max_in_progress int4;
+
+CREATE INDEX assignments_task_id_init_idx
+ON assignments (task_id)
+WHERE state = 'init';
+
+-- Daily quotas are updated by validate_submit instead.
+DROP TRIGGER update_user_quotas_daily_quota_after_update ON results;
+DROP FUNCTION update_user_quotas_daily_quota;
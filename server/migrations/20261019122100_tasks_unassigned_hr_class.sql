@@ -0,0 +1,4 @@
+-- Pinned tasks without live assignments, which update_expired_assignments unpins.
+CREATE INDEX tasks_unassigned_hr_class_idx
+ON tasks (id)
+WHERE hr_class IS NOT NULL AND cardinality(assignment_user_ids) = 0;
@@ -0,0 +1,5 @@
+-- Users are disabled rather than deleted, like everywhere else in the schema.
+ALTER TABLE project_members
+DROP CONSTRAINT project_members_user_id_fkey,
+ADD CONSTRAINT project_members_user_id_fkey
+FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE RESTRICT ON UPDATE RESTRICT;
@@ -0,0 +1,28 @@
+use clap::{Args, Parser, Subcommand};
+
+#[derive(Debug, Parser)]
+#[command(name = "Clusterizer server")]
+#[command(version)]
+pub struct ServerArgs {
+    /// Serves the API if no command is given
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    /// Serve the API, if the database schema is up to date
+    Serve,
+    /// Apply the migrations embedded in this binary to the database
+    Migrate,
+    /// Create an admin user and print its API key
+    CreateAdmin(CreateAdminArgs),
+    /// Check the configuration and the database schema, without serving
+    CheckConfig,
+}
+
+#[derive(Debug, Args)]
+pub struct CreateAdminArgs {
+    #[arg(long, short)]
+    pub name: String,
+}
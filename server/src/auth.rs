@@ -1,41 +1,95 @@
-use axum::{
-    RequestPartsExt,
-    extract::FromRequestParts,
-    http::{StatusCode, request::Parts},
-    response::{IntoResponse, Response},
-};
+use std::marker::PhantomData;
+
+use axum::{RequestPartsExt, extract::FromRequestParts, http::request::Parts};
 use axum_extra::{
     TypedHeader,
     headers::{Authorization, authorization::Bearer},
 };
 use base64::prelude::*;
 use clusterizer_common::{
-    records::{Select, User},
-    types::Id,
+    errors::AuthError,
+    records::{ApiKey, Project, Select, User, Webhook},
+    types::{Id, Scope},
 };
 use hmac::{Hmac, KeyInit, Mac};
 use sha2::Sha256;
 
-use crate::state::AppState;
+use crate::{
+    result::{AppError, ResultExt},
+    state::AppState,
+};
 
-pub struct Auth(pub Id<User>);
+/// The user making the request and the scopes of their API key. `S` is the scope the key needs
+/// for the route; keys without scopes (`None`) can do everything their user can.
+pub struct Auth<S>(pub Id<User>, pub Option<Vec<Scope>>, pub PhantomData<S>);
 
-pub enum AuthRejection {
-    BadApiKey,
-    UserDisabled,
+pub trait RequiredScope {
+    /// Whether `scope` satisfies this requirement. Project scopes match for any project, the
+    /// route has to check the project itself with [`Auth::permits`].
+    fn matches(scope: &Scope) -> bool;
 }
 
-impl IntoResponse for AuthRejection {
-    fn into_response(self) -> Response {
-        match self {
-            Self::BadApiKey => (StatusCode::BAD_REQUEST, "Bad API key provided").into_response(),
-            Self::UserDisabled => (StatusCode::BAD_REQUEST, "User is disabled").into_response(),
-        }
+pub trait RequiredProjectScope: RequiredScope {
+    fn scope(project_id: Id<Project>) -> Scope;
+}
+
+pub mod scope {
+    use clusterizer_common::{
+        records::Project,
+        types::{Id, Scope},
+    };
+
+    use super::{RequiredProjectScope, RequiredScope};
+
+    macro_rules! scope {
+        ($ident:ident) => {
+            pub struct $ident;
+
+            impl RequiredScope for $ident {
+                fn matches(scope: &Scope) -> bool {
+                    matches!(scope, Scope::$ident)
+                }
+            }
+        };
+        ($ident:ident(project)) => {
+            pub struct $ident;
+
+            impl RequiredScope for $ident {
+                fn matches(scope: &Scope) -> bool {
+                    matches!(scope, Scope::$ident(_))
+                }
+            }
+
+            impl RequiredProjectScope for $ident {
+                fn scope(project_id: Id<Project>) -> Scope {
+                    Scope::$ident(project_id)
+                }
+            }
+        };
     }
+
+    scope!(TasksFetch);
+    scope!(ResultsSubmit);
+    scope!(FilesCreate);
+    scope!(TeamsManage);
+    scope!(AccountManage);
+    scope!(UsersManage);
+    scope!(EventsRead);
+    scope!(ProjectValidate(project));
+    scope!(ProjectAssimilate(project));
+    scope!(ProjectManage(project));
 }
 
-impl FromRequestParts<AppState> for Auth {
-    type Rejection = AuthRejection;
+impl<S: RequiredProjectScope> Auth<S> {
+    pub fn permits(&self, project_id: Id<Project>) -> bool {
+        self.1
+            .as_ref()
+            .is_none_or(|scopes| scopes.contains(&S::scope(project_id)))
+    }
+}
+
+impl<S: RequiredScope> FromRequestParts<AppState> for Auth<S> {
+    type Rejection = AppError<AuthError>;
 
     async fn from_request_parts(
         parts: &mut Parts,
@@ -44,51 +98,86 @@ impl FromRequestParts<AppState> for Auth {
         let TypedHeader(Authorization(bearer)): TypedHeader<Authorization<Bearer>> = parts
             .extract()
             .await
-            .map_err(|_| AuthRejection::BadApiKey)?;
+            .map_err(|_| AppError::Specific(AuthError::MissingApiKey))?;
 
-        let mut api_key_bytes = [0; 40];
-        let mut user_id_bytes = [0; 8];
+        let api_key_id = api_key_id(state, bearer.token())
+            .ok_or(AppError::Specific(AuthError::InvalidApiKey))?;
 
-        let length = BASE64_STANDARD
-            .decode_slice(bearer.token(), &mut api_key_bytes)
-            .map_err(|_| AuthRejection::BadApiKey)?;
+        let api_key = api_key_id
+            .select()
+            .fetch_one(&state.pool)
+            .await
+            .map_not_found(AuthError::InvalidApiKey)?;
 
-        if length != api_key_bytes.len() {
-            Err(AuthRejection::BadApiKey)?;
+        if api_key.revoked_at.is_some() {
+            Err(AppError::Specific(AuthError::RevokedApiKey))?;
         }
 
-        hmac(state, &api_key_bytes[..8])
-            .verify_slice(&api_key_bytes[8..])
-            .map_err(|_| AuthRejection::BadApiKey)?;
-
-        user_id_bytes.copy_from_slice(&api_key_bytes[..8]);
+        if api_key
+            .scopes
+            .as_ref()
+            .is_some_and(|scopes| !scopes.iter().any(S::matches))
+        {
+            Err(AppError::Specific(AuthError::MissingScope))?;
+        }
 
-        let user_id: Id<User> = i64::from_le_bytes(user_id_bytes).into();
-        let user = user_id
-            .select()
-            .fetch_one(&state.pool)
-            .await
-            .map_err(|_| AuthRejection::BadApiKey)?;
+        let user = api_key.user_id.select().fetch_one(&state.pool).await?;
 
         if user.disabled_at.is_some() {
-            Err(AuthRejection::UserDisabled)?;
+            Err(AppError::Specific(AuthError::UserDisabled))?;
         }
 
-        Ok(Auth(user_id))
+        Ok(Auth(user.id, api_key.scopes, PhantomData))
     }
 }
 
-pub fn api_key(state: &AppState, user_id: Id<User>) -> String {
-    let user_id_bytes = user_id.raw().to_le_bytes();
-    let hmac_bytes = hmac(state, &user_id_bytes).finalize().into_bytes();
+/// The API key a bearer token belongs to, if it was issued by this server. Whether the key is
+/// revoked is not checked.
+pub fn api_key_id(state: &AppState, token: &str) -> Option<Id<ApiKey>> {
     let mut api_key_bytes = [0; 40];
+    let mut api_key_id_bytes = [0; 8];
+
+    let length = BASE64_STANDARD
+        .decode_slice(token, &mut api_key_bytes)
+        .ok()?;
+
+    if length != api_key_bytes.len() {
+        None?;
+    }
+
+    hmac(state, &api_key_bytes[..8])
+        .verify_slice(&api_key_bytes[8..])
+        .ok()?;
+
+    api_key_id_bytes.copy_from_slice(&api_key_bytes[..8]);
 
-    api_key_bytes[..8].copy_from_slice(&user_id_bytes);
+    Some(i64::from_le_bytes(api_key_id_bytes).into())
+}
+
+/// The bearer token for an API key. It is derived from the server secret, so only the key's
+/// record has to be stored and revoking it invalidates the token.
+pub fn api_key(state: &AppState, api_key_id: Id<ApiKey>) -> String {
+    let api_key_id_bytes = api_key_id.raw().to_le_bytes();
+    let hmac_bytes = hmac(state, &api_key_id_bytes).finalize().into_bytes();
+    let mut api_key_bytes = [0; 40];
+
+    api_key_bytes[..8].copy_from_slice(&api_key_id_bytes);
     api_key_bytes[8..].copy_from_slice(&hmac_bytes);
 
     BASE64_STANDARD.encode(api_key_bytes)
 }
 
+/// The key a webhook's deliveries are signed with. It is derived from the server secret, so it
+/// does not have to be stored.
+pub fn webhook_secret(state: &AppState, webhook_id: Id<Webhook>) -> String {
+    let hmac_bytes = hmac(state, b"webhook")
+        .chain_update(webhook_id.raw().to_le_bytes())
+        .finalize()
+        .into_bytes();
+
+    BASE64_STANDARD.encode(hmac_bytes)
+}
+
 fn hmac(state: &AppState, bytes: &[u8]) -> Hmac<Sha256> {
     Hmac::new_from_slice(&state.secret)
         .unwrap()
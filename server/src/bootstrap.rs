@@ -0,0 +1,27 @@
+use clusterizer_common::records::{ApiKeyBuilder, Insert, UserBuilder, user::UpdateUser};
+
+use crate::{args::CreateAdminArgs, auth, state::AppState};
+
+/// Creates the first admin, who can promote others through the API.
+pub async fn create_admin(state: AppState, args: CreateAdminArgs) -> sqlx::Result<String> {
+    let mut tx = state.pool.begin().await?;
+
+    let user_id = UserBuilder { name: args.name }
+        .insert()
+        .fetch_one(&mut *tx)
+        .await?;
+
+    user_id.update_is_admin(true).execute(&mut *tx).await?;
+
+    let api_key_id = ApiKeyBuilder {
+        user_id,
+        scopes: None,
+    }
+    .insert()
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(auth::api_key(&state, api_key_id))
+}
@@ -0,0 +1,62 @@
+use crate::rate_limit::{DEFAULT_RATE_LIMITS, RateLimiter};
+
+/// The configuration of the server, read from the environment or a `.env` file. See
+/// `example.env`.
+pub struct Config {
+    pub database_url: String,
+    pub secret: String,
+    /// Only needed to serve, so the other commands work without it.
+    pub address: Option<String>,
+    pub rate_limits: String,
+    pub trust_forwarded_for: bool,
+}
+
+impl Config {
+    pub fn from_env() -> Result<Self, String> {
+        let secret = required("CLUSTERIZER_SECRET")?;
+
+        if secret.is_empty() {
+            Err("CLUSTERIZER_SECRET must not be empty".to_string())?;
+        }
+
+        let trust_forwarded_for = match optional("CLUSTERIZER_TRUST_FORWARDED_FOR")?.as_deref() {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(value) => Err(format!(
+                "CLUSTERIZER_TRUST_FORWARDED_FOR must be true or false, not {value}"
+            ))?,
+        };
+
+        Ok(Self {
+            database_url: required("DATABASE_URL")?,
+            secret,
+            address: optional("CLUSTERIZER_ADDRESS")?,
+            rate_limits: optional("CLUSTERIZER_RATE_LIMITS")?
+                .unwrap_or_else(|| DEFAULT_RATE_LIMITS.to_string()),
+            trust_forwarded_for,
+        })
+    }
+
+    pub fn address(&self) -> Result<&str, String> {
+        self.address
+            .as_deref()
+            .ok_or_else(|| "CLUSTERIZER_ADDRESS is not set".to_string())
+    }
+
+    pub fn rate_limiter(&self) -> Result<RateLimiter, String> {
+        RateLimiter::new(&self.rate_limits, self.trust_forwarded_for)
+            .map_err(|err| format!("invalid CLUSTERIZER_RATE_LIMITS: {err}"))
+    }
+}
+
+fn required(name: &str) -> Result<String, String> {
+    optional(name)?.ok_or_else(|| format!("{name} is not set"))
+}
+
+fn optional(name: &str) -> Result<Option<String>, String> {
+    match dotenvy::var(name) {
+        Ok(value) => Ok(Some(value)),
+        Err(dotenvy::Error::EnvVar(std::env::VarError::NotPresent)) => Ok(None),
+        Err(err) => Err(format!("could not read {name}: {err}")),
+    }
+}
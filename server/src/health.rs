@@ -0,0 +1,149 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use sqlx::{
+    PgPool,
+    migrate::{Migrate, MigrateError, Migrator},
+};
+use tracing::warn;
+
+/// The migrations in `server/migrations`, embedded at build time.
+pub static MIGRATOR: Migrator = sqlx::migrate!();
+
+/// When each background task last completed a run, so readiness can tell whether they are stuck.
+#[derive(Default)]
+pub struct Heartbeats(Mutex<HashMap<&'static str, Instant>>);
+
+impl Heartbeats {
+    pub fn beat(&self, task: &'static str) {
+        self.0.lock().unwrap().insert(task, Instant::now());
+    }
+
+    /// The time since the task last completed a run, or `None` if it never has.
+    pub fn elapsed(&self, task: &'static str) -> Option<Duration> {
+        self.0
+            .lock()
+            .unwrap()
+            .get(task)
+            .map(|instant| instant.elapsed())
+    }
+}
+
+/// How the migrations applied to the database compare to the embedded ones.
+pub struct SchemaStatus {
+    /// Embedded migrations that have not been applied yet.
+    pub pending: Vec<i64>,
+    /// Applied migrations that this build does not know, so the schema is newer than the server.
+    pub unknown: Vec<i64>,
+}
+
+impl SchemaStatus {
+    /// Compares the versions of the embedded and applied migrations.
+    pub fn new(embedded: &[i64], applied: &[i64]) -> Self {
+        Self {
+            pending: embedded
+                .iter()
+                .copied()
+                .filter(|version| !applied.contains(version))
+                .collect(),
+            unknown: applied
+                .iter()
+                .copied()
+                .filter(|version| !embedded.contains(version))
+                .collect(),
+        }
+    }
+
+    /// Fails if the schema is outdated. A newer schema is only warned about, since migrations are
+    /// meant to keep older servers working during a rollout.
+    pub fn check(&self) -> Result<(), String> {
+        if !self.unknown.is_empty() {
+            warn!(
+                "The database has migrations this server does not know: {:?}.",
+                self.unknown
+            );
+        }
+
+        if !self.pending.is_empty() {
+            Err(format!(
+                "the database schema is outdated, run `clusterizer-server migrate` to apply {:?}",
+                self.pending
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+pub async fn schema_status(pool: &PgPool) -> Result<SchemaStatus, MigrateError> {
+    let mut conn = pool.acquire().await?;
+
+    // A fresh database has no migrations table until the first migration.
+    let has_migrations_table = sqlx::query_scalar_unchecked!(
+        r#"
+        SELECT
+            to_regclass('_sqlx_migrations') IS NOT NULL "exists!"
+        "#
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    let applied: Vec<_> = if has_migrations_table {
+        conn.list_applied_migrations()
+            .await?
+            .into_iter()
+            .map(|migration| migration.version)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let embedded: Vec<_> = MIGRATOR.iter().map(|migration| migration.version).collect();
+
+    Ok(SchemaStatus::new(&embedded, &applied))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn up_to_date() {
+        let status = SchemaStatus::new(&[1, 2, 3], &[1, 2, 3]);
+
+        assert!(status.pending.is_empty());
+        assert!(status.unknown.is_empty());
+        assert!(status.check().is_ok());
+    }
+
+    #[test]
+    fn outdated() {
+        let status = SchemaStatus::new(&[1, 2, 3], &[1]);
+
+        assert_eq!(status.pending, vec![2, 3]);
+        assert!(status.unknown.is_empty());
+        assert!(status.check().is_err());
+
+        assert!(SchemaStatus::new(&[1, 2, 3], &[]).check().is_err());
+    }
+
+    #[test]
+    fn newer() {
+        let status = SchemaStatus::new(&[1, 2], &[1, 2, 3]);
+
+        assert!(status.pending.is_empty());
+        assert_eq!(status.unknown, vec![3]);
+        assert!(status.check().is_ok());
+    }
+
+    #[test]
+    fn embedded_migrations_are_known() {
+        let embedded: Vec<_> = MIGRATOR.iter().map(|migration| migration.version).collect();
+
+        assert!(!embedded.is_empty());
+        assert!(SchemaStatus::new(&embedded, &embedded).check().is_ok());
+    }
+}
@@ -1,67 +1,220 @@
+mod args;
 mod auth;
+mod bootstrap;
+mod config;
+mod health;
+mod monitoring;
+mod projects;
+mod quotas;
+mod rate_limit;
 mod result;
 mod routes;
 mod state;
 mod tasks;
+mod teams;
+mod users;
+mod webhooks;
 
+use std::{
+    net::{SocketAddr, ToSocketAddrs},
+    process::ExitCode,
+    sync::Arc,
+};
+
+use args::{Commands, ServerArgs};
 use axum::{
-    Router,
+    Router, middleware,
     routing::{get, post},
 };
 use clusterizer_common::{
     records::{
-        Assignment, File, Platform, Project, ProjectVersion, Record, Result, Select, Task, User,
+        Assignment, Credit, File, Platform, Project, ProjectMember, ProjectVersion, Record, Result,
+        Select, Task, Team, User,
     },
     types::Id,
 };
 
+use clap::Parser;
+use config::Config;
 use serde::{Serialize, de::DeserializeOwned};
 use sqlx::PgPool;
 use state::AppState;
-use tokio::net::TcpListener;
+use tokio::{net::TcpListener, sync::broadcast};
 use tower_http::trace::TraceLayer;
+use tracing::{error, info};
 
 #[tokio::main]
-async fn main() {
+async fn main() -> ExitCode {
     tracing_subscriber::fmt::init();
 
-    let database_url = dotenvy::var("DATABASE_URL").unwrap();
-    let secret = dotenvy::var("CLUSTERIZER_SECRET").unwrap();
-    let address = dotenvy::var("CLUSTERIZER_ADDRESS").unwrap();
+    let args = ServerArgs::parse();
+
+    match run(args.command.unwrap_or(Commands::Serve)).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            error!("{err}.");
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(command: Commands) -> std::result::Result<(), String> {
+    let config = Config::from_env()?;
+
+    let pool = PgPool::connect(&config.database_url)
+        .await
+        .map_err(|err| format!("could not connect to the database: {err}"))?;
+
+    let schema_status = health::schema_status(&pool)
+        .await
+        .map_err(|err| format!("could not read the applied migrations: {err}"))?;
+
+    match command {
+        Commands::Serve => {
+            schema_status.check()?;
+
+            let address = config.address()?;
+            let listener = TcpListener::bind(address)
+                .await
+                .map_err(|err| format!("could not listen on {address}: {err}"))?;
+
+            let state = app_state(&config, pool)?;
 
-    let state = AppState {
-        pool: PgPool::connect(&database_url).await.unwrap(),
-        secret: secret.into_bytes(),
-    };
+            info!("Listening on {address}.");
 
-    tokio::join!(
-        serve_task(state.clone(), address),
-        tasks::update_expired_assignments(state.clone()),
-    );
+            tokio::join!(
+                serve_task(state.clone(), listener),
+                tasks::update_expired_assignments(state.clone()),
+                tasks::create_follow_up_tasks(state.clone()),
+                tasks::deliver_webhooks(state.clone()),
+                tasks::listen_record_changes(state.clone()),
+                tasks::refresh_stats(state.clone()),
+                tasks::prune_rate_limits(state.clone()),
+                tasks::update_metrics(state.clone()),
+            );
+        }
+        Commands::Migrate => {
+            health::MIGRATOR
+                .run(&pool)
+                .await
+                .map_err(|err| format!("could not migrate the database: {err}"))?;
+
+            info!("Applied {} migrations.", schema_status.pending.len());
+        }
+        Commands::CreateAdmin(args) => {
+            schema_status.check()?;
+
+            let state = app_state(&config, pool)?;
+            let api_key = bootstrap::create_admin(state, args)
+                .await
+                .map_err(|err| format!("could not create the admin: {err}"))?;
+
+            println!("{api_key}");
+        }
+        Commands::CheckConfig => {
+            config.rate_limiter()?;
+
+            let address = config.address()?;
+
+            address
+                .to_socket_addrs()
+                .map_err(|err| format!("invalid CLUSTERIZER_ADDRESS: {err}"))?;
+
+            schema_status.check()?;
+
+            println!("The configuration is valid and the database schema is up to date.");
+        }
+    }
+
+    Ok(())
 }
 
-async fn serve_task(state: AppState, address: String) {
+fn app_state(config: &Config, pool: PgPool) -> std::result::Result<AppState, String> {
+    Ok(AppState {
+        pool,
+        secret: config.secret.clone().into_bytes(),
+        events: broadcast::channel(1024).0,
+        rate_limiter: Arc::new(config.rate_limiter()?),
+        metrics: monitoring::install()
+            .map_err(|err| format!("could not install the metrics recorder: {err}"))?,
+        heartbeats: Arc::default(),
+    })
+}
+
+async fn serve_task(state: AppState, listener: TcpListener) {
     let app = Router::new()
         .merge(record_router::<File>())
         .merge(record_router::<User>())
         .merge(record_router::<Project>())
+        .merge(record_router::<ProjectMember>())
         .merge(record_router::<Platform>())
         .merge(record_router::<ProjectVersion>())
         .merge(record_router::<Task>())
         .merge(record_router::<Assignment>())
         .merge(record_router::<Result>())
+        .merge(record_router::<Credit>())
+        .merge(record_router::<Team>())
         .route("/register", post(routes::register))
         .route("/fetch_tasks", post(routes::fetch_tasks))
         .route("/submit_result/{id}", post(routes::submit_result))
         .route("/validate_fetch/{id}", get(routes::validate_fetch))
         .route("/validate_submit", post(routes::validate_submit))
         .route("/files", post(routes::create_file))
+        .route("/webhooks", post(routes::create_webhook))
+        .route("/events", get(routes::events))
+        .route("/stats/users", get(routes::user_stats))
+        .route("/stats/projects/{id}", get(routes::project_stats))
+        .route("/stats/global", get(routes::global_stats))
+        .route("/healthz", get(routes::healthz))
+        .route("/readyz", get(routes::readyz))
+        .route("/metrics", get(routes::metrics))
+        .route("/stats/teams", get(routes::team_stats))
+        .route("/teams", post(routes::create_team))
+        .route("/teams/leave", post(routes::leave_team))
+        .route("/teams/{id}/join", post(routes::join_team))
+        .route("/teams/{id}/kick", post(routes::kick_team_member))
+        .route("/users/me/hosts", get(routes::hosts))
+        .route("/users/me/quotas", get(routes::user_quotas))
+        .route(
+            "/users/me/api_keys",
+            get(routes::api_keys).post(routes::create_api_key),
+        )
+        .route(
+            "/users/me/api_keys/{id}/revoke",
+            post(routes::revoke_api_key),
+        )
+        .route("/projects/{id}/members", post(routes::set_project_member))
+        .route(
+            "/projects/{id}/members/remove",
+            post(routes::remove_project_member),
+        )
+        .route("/admin/users/{id}/admin", post(routes::set_user_admin))
+        .route(
+            "/admin/users/{id}/disabled",
+            post(routes::set_user_disabled),
+        )
+        .route("/admin/users/{id}/activity", get(routes::user_activity))
+        .route("/set_beta_opt_in", post(routes::set_beta_opt_in))
+        .route("/set_task_priority/{id}", post(routes::set_task_priority))
+        .route(
+            "/assimilate/{id}",
+            get(routes::assimilate_fetch).post(routes::assimilate_submit),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            rate_limit::rate_limit,
+        ))
+        .route_layer(middleware::from_fn(monitoring::track_requests))
         .layer(TraceLayer::new_for_http())
         .with_state(state);
 
-    let listener = TcpListener::bind(address).await.unwrap();
-
-    axum::serve(listener, app).await.unwrap()
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .unwrap()
 }
 
 fn record_router<T>() -> Router<AppState>
@@ -0,0 +1,65 @@
+use std::time::Instant;
+
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use metrics::{counter, gauge, histogram};
+use metrics_exporter_prometheus::{BuildError, Matcher, PrometheusBuilder, PrometheusHandle};
+use sqlx::PgPool;
+
+/// Bucket bounds of the request latency histogram, in seconds.
+const REQUEST_DURATION_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Installs the global metrics recorder. Its upkeep is run by the `update_metrics` task.
+pub fn install() -> Result<PrometheusHandle, BuildError> {
+    PrometheusBuilder::new()
+        .set_buckets_for_metric(
+            Matcher::Full("http_request_duration_seconds".to_string()),
+            REQUEST_DURATION_BUCKETS,
+        )?
+        .install_recorder()
+}
+
+/// Counts requests and measures their latency. Requests are labeled by their route rather than
+/// their path, so ids do not create a series each.
+pub async fn track_requests(matched_path: MatchedPath, request: Request, next: Next) -> Response {
+    let method = request.method().to_string();
+    let route = matched_path.as_str().to_string();
+    let start = Instant::now();
+
+    let response = next.run(request).await;
+
+    let status = response.status().as_u16().to_string();
+
+    counter!(
+        "http_requests_total",
+        "method" => method.clone(),
+        "route" => route.clone(),
+        "status" => status,
+    )
+    .increment(1);
+
+    histogram!(
+        "http_request_duration_seconds",
+        "method" => method,
+        "route" => route,
+    )
+    .record(start.elapsed());
+
+    response
+}
+
+/// Records the connections of the pool, which are cheap to read on every scrape.
+pub fn record_pool(pool: &PgPool) {
+    let size = pool.size();
+    let idle = pool.num_idle() as u32;
+
+    gauge!("db_pool_connections").set(size);
+    gauge!("db_pool_idle_connections").set(idle);
+    gauge!("db_pool_active_connections").set(size.saturating_sub(idle));
+    gauge!("db_pool_max_connections").set(pool.options().get_max_connections());
+}
@@ -0,0 +1,88 @@
+use clusterizer_common::{
+    records::{Project, User},
+    types::{Id, ProjectRole},
+};
+use sqlx::{PgConnection, PgExecutor};
+
+/// Whether the user is a member of the project with at least the given role.
+pub async fn has_role(
+    executor: impl PgExecutor<'_>,
+    project_id: Id<Project>,
+    user_id: Id<User>,
+    role: ProjectRole,
+) -> sqlx::Result<bool> {
+    sqlx::query_scalar_unchecked!(
+        r#"
+        SELECT EXISTS (
+            SELECT
+                1
+            FROM
+                project_members
+            WHERE
+                project_id = $1
+                AND user_id = $2
+                AND role >= $3
+        ) "exists!"
+        "#,
+        project_id,
+        user_id,
+        role,
+    )
+    .fetch_one(executor)
+    .await
+}
+
+/// Whether the user is the only owner of the project. The owners are locked until the end of the
+/// transaction, so two owners cannot step down at the same time.
+pub async fn is_last_owner(
+    conn: &mut PgConnection,
+    project_id: Id<Project>,
+    user_id: Id<User>,
+) -> sqlx::Result<bool> {
+    let owner_user_ids = sqlx::query_scalar_unchecked!(
+        r#"
+        SELECT
+            user_id "user_id: Id<User>"
+        FROM
+            project_members
+        WHERE
+            project_id = $1
+            AND role = 'owner'
+        FOR UPDATE
+        "#,
+        project_id,
+    )
+    .fetch_all(conn)
+    .await?;
+
+    Ok(owner_user_ids == [user_id])
+}
+
+/// Whether a user has enough consecutive valid results for adaptive replication to accept their
+/// results at quorum 1. Projects without a threshold trust no one.
+pub fn is_trusted(
+    adaptive_replication_threshold: Option<i32>,
+    consecutive_valid_result_count: i32,
+) -> bool {
+    adaptive_replication_threshold
+        .is_some_and(|threshold| consecutive_valid_result_count >= threshold)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trust_needs_a_threshold() {
+        assert!(!is_trusted(None, 0));
+        assert!(!is_trusted(None, 1000));
+    }
+
+    #[test]
+    fn trust_starts_at_the_threshold() {
+        assert!(!is_trusted(Some(10), 0));
+        assert!(!is_trusted(Some(10), 9));
+        assert!(is_trusted(Some(10), 10));
+        assert!(is_trusted(Some(10), 11));
+    }
+}
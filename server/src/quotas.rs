@@ -0,0 +1,140 @@
+use clusterizer_common::{
+    records::{Project, Result, UserQuota},
+    types::{Id, ResultState},
+};
+use sqlx::PgConnection;
+
+/// Updates the daily quotas of the users of results that were just validated, see
+/// [`next_daily_quota`]. Users without a quota start from the full quota of the project.
+pub async fn update(
+    conn: &mut PgConnection,
+    project: &Project,
+    result_ids: &[Id<Result>],
+) -> sqlx::Result<()> {
+    let Some(project_daily_quota) = project.daily_quota else {
+        return Ok(());
+    };
+
+    sqlx::query_unchecked!(
+        r#"
+        INSERT INTO user_quotas (
+            user_id,
+            project_id,
+            daily_quota
+        )
+        SELECT
+            a.user_id,
+            $2,
+            $3
+        FROM
+            results r,
+            assignments a
+        WHERE
+            r.id = ANY($1)
+            AND a.id = r.assignment_id
+        ON CONFLICT (user_id, project_id) DO NOTHING
+        "#,
+        result_ids,
+        project.id,
+        project_daily_quota,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    // The quotas are locked in order, so validations of other tasks wait rather than losing an
+    // update.
+    let quotas = sqlx::query_unchecked!(
+        r#"
+        SELECT
+            q.id "id: Id<UserQuota>",
+            q.daily_quota,
+            r.state "state: ResultState"
+        FROM
+            results r,
+            assignments a,
+            user_quotas q
+        WHERE
+            r.id = ANY($1)
+            AND a.id = r.assignment_id
+            AND q.user_id = a.user_id
+            AND q.project_id = $2
+        ORDER BY
+            q.id
+        FOR UPDATE OF q
+        "#,
+        result_ids,
+        project.id,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    for quota in quotas {
+        sqlx::query_unchecked!(
+            r#"
+            UPDATE
+                user_quotas
+            SET
+                updated_at = now(),
+                daily_quota = $2
+            WHERE
+                id = $1
+            "#,
+            quota.id,
+            next_daily_quota(quota.daily_quota, project_daily_quota, quota.state),
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// A valid result doubles the quota up to the quota of the project, an invalid or error result
+/// halves it, but never below one.
+fn next_daily_quota(daily_quota: i32, project_daily_quota: i32, state: ResultState) -> i32 {
+    match state {
+        ResultState::Valid => daily_quota.saturating_mul(2).min(project_daily_quota),
+        ResultState::Invalid | ResultState::Error => (daily_quota / 2).max(1),
+        ResultState::Init | ResultState::Inconclusive => daily_quota,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_results_double_the_quota() {
+        assert_eq!(next_daily_quota(1, 100, ResultState::Valid), 2);
+        assert_eq!(next_daily_quota(25, 100, ResultState::Valid), 50);
+    }
+
+    #[test]
+    fn valid_results_clamp_to_the_project_quota() {
+        assert_eq!(next_daily_quota(60, 100, ResultState::Valid), 100);
+        assert_eq!(next_daily_quota(100, 100, ResultState::Valid), 100);
+        assert_eq!(next_daily_quota(200, 100, ResultState::Valid), 100);
+        assert_eq!(
+            next_daily_quota(i32::MAX, i32::MAX, ResultState::Valid),
+            i32::MAX
+        );
+    }
+
+    #[test]
+    fn invalid_and_error_results_halve_the_quota() {
+        assert_eq!(next_daily_quota(100, 100, ResultState::Invalid), 50);
+        assert_eq!(next_daily_quota(51, 100, ResultState::Error), 25);
+    }
+
+    #[test]
+    fn quota_never_drops_below_one() {
+        assert_eq!(next_daily_quota(1, 100, ResultState::Invalid), 1);
+        assert_eq!(next_daily_quota(2, 100, ResultState::Error), 1);
+    }
+
+    #[test]
+    fn unfinished_results_keep_the_quota() {
+        assert_eq!(next_daily_quota(10, 100, ResultState::Init), 10);
+        assert_eq!(next_daily_quota(10, 100, ResultState::Inconclusive), 10);
+    }
+}
@@ -0,0 +1,276 @@
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    str::FromStr,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use axum::{
+    extract::{ConnectInfo, MatchedPath, Request, State},
+    http::{HeaderMap, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use axum_extra::headers::{Authorization, HeaderMapExt, authorization::Bearer};
+use clusterizer_common::{
+    records::{ApiKey, Select, User},
+    types::Id,
+};
+
+use crate::{auth, state::AppState};
+
+/// The budgets used if `CLUSTERIZER_RATE_LIMITS` is not set.
+pub const DEFAULT_RATE_LIMITS: &str = "/register=10/3600,/fetch_tasks=120/60,*=1200/60";
+
+/// How many requests a client can make to a route. Up to `requests` can be made at once, after
+/// which they become available again evenly over `period`.
+#[derive(Copy, Clone, Debug)]
+pub struct Budget {
+    requests: u32,
+    period: Duration,
+}
+
+impl FromStr for Budget {
+    type Err = String;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let err = || format!("invalid budget: {src}");
+        let (requests, seconds) = src.split_once('/').ok_or_else(err)?;
+        let requests = requests.parse().map_err(|_| err())?;
+        let seconds = seconds.parse().map_err(|_| err())?;
+
+        if requests == 0 || seconds == 0 {
+            Err(err())?;
+        }
+
+        Ok(Self {
+            requests,
+            period: Duration::from_secs(seconds),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+enum Client {
+    User(Id<User>),
+    Ip(IpAddr),
+}
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+pub struct RateLimiter {
+    // By route path, with `*` for all other routes. Routes without a budget are not limited.
+    budgets: HashMap<String, Budget>,
+    buckets: Mutex<HashMap<(String, Client), Bucket>>,
+    // The user of an API key never changes, so it only has to be looked up once.
+    user_ids: Mutex<HashMap<Id<ApiKey>, Id<User>>>,
+    trust_forwarded_for: bool,
+}
+
+impl RateLimiter {
+    /// Parses budgets written as `route=requests/seconds`, separated by commas.
+    pub fn new(budgets: &str, trust_forwarded_for: bool) -> Result<Self, String> {
+        let budgets = budgets
+            .split(',')
+            .map(str::trim)
+            .filter(|budget| !budget.is_empty())
+            .map(|budget| {
+                let (route, budget) = budget
+                    .split_once('=')
+                    .ok_or_else(|| format!("invalid rate limit: {budget}"))?;
+
+                Ok((route.to_string(), budget.parse()?))
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Self {
+            budgets,
+            buckets: Mutex::default(),
+            user_ids: Mutex::default(),
+            trust_forwarded_for,
+        })
+    }
+
+    /// Takes a request from the client's budget for the route. If it is used up, returns how long
+    /// until the next request can be made.
+    fn take(&self, route: &str, client: Client) -> Result<(), Duration> {
+        let Some((route, budget)) = self
+            .budgets
+            .get_key_value(route)
+            .or_else(|| self.budgets.get_key_value("*"))
+        else {
+            return Ok(());
+        };
+
+        let now = Instant::now();
+        let requests = budget.requests as f64;
+        let refill_rate = requests / budget.period.as_secs_f64();
+
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry((route.clone(), client)).or_insert(Bucket {
+            tokens: requests,
+            updated_at: now,
+        });
+
+        bucket.tokens = (bucket.tokens
+            + now.duration_since(bucket.updated_at).as_secs_f64() * refill_rate)
+            .min(requests);
+        bucket.updated_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / refill_rate))
+        }
+    }
+
+    /// Forgets buckets that have refilled, as they behave the same as new ones.
+    pub fn prune(&self) {
+        let now = Instant::now();
+
+        self.buckets.lock().unwrap().retain(|(route, _), bucket| {
+            let budget = self.budgets[route];
+            let refilled = bucket.tokens
+                + now.duration_since(bucket.updated_at).as_secs_f64() * budget.requests as f64
+                    / budget.period.as_secs_f64();
+
+            refilled < budget.requests as f64
+        });
+    }
+
+    async fn client(&self, state: &AppState, headers: &HeaderMap, address: SocketAddr) -> Client {
+        if let Some(api_key_id) = headers
+            .typed_get::<Authorization<Bearer>>()
+            .and_then(|Authorization(bearer)| auth::api_key_id(state, bearer.token()))
+        {
+            let user_id = self.user_ids.lock().unwrap().get(&api_key_id).copied();
+
+            let user_id = match user_id {
+                Some(user_id) => Some(user_id),
+                None => api_key_id
+                    .select()
+                    .fetch_optional(&state.pool)
+                    .await
+                    .ok()
+                    .flatten()
+                    .map(|api_key| api_key.user_id),
+            };
+
+            if let Some(user_id) = user_id {
+                self.user_ids.lock().unwrap().insert(api_key_id, user_id);
+
+                return Client::User(user_id);
+            }
+        }
+
+        // Behind a reverse proxy, the address of the client is the last one in the header, which
+        // the proxy appended. Earlier entries come from the client and can be anything.
+        let forwarded_for = self
+            .trust_forwarded_for
+            .then(|| headers.get("x-forwarded-for"))
+            .flatten()
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.rsplit(',').next())
+            .and_then(|ip| ip.trim().parse().ok());
+
+        Client::Ip(forwarded_for.unwrap_or(address.ip()))
+    }
+}
+
+pub async fn rate_limit(
+    State(state): State<AppState>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    matched_path: MatchedPath,
+    request: Request,
+    next: Next,
+) -> Response {
+    let rate_limiter = &state.rate_limiter;
+    let client = rate_limiter
+        .client(&state, request.headers(), address)
+        .await;
+
+    match rate_limiter.take(matched_path.as_str(), client) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(
+                header::RETRY_AFTER,
+                retry_after.as_secs().max(1).to_string(),
+            )],
+        )
+            .into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_from_str() {
+        let budget: Budget = "120/60".parse().unwrap();
+
+        assert_eq!(budget.requests, 120);
+        assert_eq!(budget.period, Duration::from_secs(60));
+
+        for src in [
+            "", "120", "120/", "/60", "0/60", "120/0", "-1/60", "a/60", "120/60/1",
+        ] {
+            assert!(src.parse::<Budget>().is_err(), "{src}");
+        }
+    }
+
+    #[test]
+    fn new_rejects_invalid_rate_limits() {
+        assert!(RateLimiter::new(DEFAULT_RATE_LIMITS, false).is_ok());
+        assert!(RateLimiter::new("", false).is_ok());
+        assert!(RateLimiter::new("/register", false).is_err());
+        assert!(RateLimiter::new("/register=10", false).is_err());
+    }
+
+    #[test]
+    fn take_limits_each_client() {
+        let rate_limiter = RateLimiter::new("/register=2/60", false).unwrap();
+        let alice = Client::User(Id::from(1));
+        let bob = Client::User(Id::from(2));
+
+        assert_eq!(rate_limiter.take("/register", alice.clone()), Ok(()));
+        assert_eq!(rate_limiter.take("/register", alice.clone()), Ok(()));
+
+        let retry_after = rate_limiter.take("/register", alice).unwrap_err();
+
+        assert!(retry_after > Duration::from_secs(29));
+        assert!(retry_after <= Duration::from_secs(30));
+
+        assert_eq!(rate_limiter.take("/register", bob), Ok(()));
+    }
+
+    #[test]
+    fn take_falls_back_to_the_wildcard() {
+        let rate_limiter = RateLimiter::new("/register=1/60,*=1/60", false).unwrap();
+        let client = Client::Ip(IpAddr::from([127, 0, 0, 1]));
+
+        assert_eq!(rate_limiter.take("/register", client.clone()), Ok(()));
+        assert!(rate_limiter.take("/register", client.clone()).is_err());
+
+        // All other routes share the wildcard budget.
+        assert_eq!(rate_limiter.take("/projects", client.clone()), Ok(()));
+        assert!(rate_limiter.take("/tasks", client).is_err());
+    }
+
+    #[test]
+    fn take_does_not_limit_routes_without_a_budget() {
+        let rate_limiter = RateLimiter::new("/register=1/60", false).unwrap();
+        let client = Client::Ip(IpAddr::from([127, 0, 0, 1]));
+
+        for _ in 0..10 {
+            assert_eq!(rate_limiter.take("/projects", client.clone()), Ok(()));
+        }
+    }
+}
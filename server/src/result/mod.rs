@@ -3,6 +3,7 @@ use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
 };
+use metrics::counter;
 use serde::Serialize;
 use status::Status;
 
@@ -46,7 +47,11 @@ impl<E: Serialize + Status> IntoResponse for AppError<E> {
     fn into_response(self) -> Response {
         match self {
             Self::Specific(err) => (err.status(), Json(err)).into_response(),
-            Self::Sqlx => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+            Self::Sqlx => {
+                counter!("sqlx_errors_total").increment(1);
+
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
+            }
         }
     }
 }
@@ -1,7 +1,11 @@
 use axum::http::StatusCode;
 use clusterizer_common::errors::{
-    CreateFileError, FetchTasksError, Infallible, NotFound, RegisterError, SubmitResultError,
-    ValidateFetchError, ValidateSubmitError,
+    AssimilateFetchError, AssimilateSubmitError, AuthError, CreateApiKeyError, CreateFileError,
+    CreateTeamError, CreateWebhookError, FetchTasksError, Infallible, JoinTeamError,
+    KickTeamMemberError, LeaveTeamError, NotFound, RegisterError, RemoveProjectMemberError,
+    RevokeApiKeyError, SetProjectMemberError, SetTaskPriorityError, SetUserAdminError,
+    SetUserDisabledError, SubmitResultError, UserActivityError, ValidateFetchError,
+    ValidateSubmitError,
 };
 
 pub trait Status {
@@ -20,6 +24,17 @@ impl Status for NotFound {
     }
 }
 
+impl Status for AuthError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::MissingApiKey | Self::InvalidApiKey | Self::RevokedApiKey => {
+                StatusCode::UNAUTHORIZED
+            }
+            Self::MissingScope | Self::UserDisabled => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
 impl Status for RegisterError {
     fn status(&self) -> StatusCode {
         StatusCode::BAD_REQUEST
@@ -64,3 +79,123 @@ impl Status for CreateFileError {
         }
     }
 }
+
+impl Status for SetTaskPriorityError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl Status for AssimilateFetchError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl Status for AssimilateSubmitError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl Status for CreateWebhookError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl Status for CreateTeamError {
+    fn status(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+}
+
+impl Status for JoinTeamError {
+    fn status(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+}
+
+impl Status for LeaveTeamError {
+    fn status(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+}
+
+impl Status for KickTeamMemberError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl Status for CreateApiKeyError {
+    fn status(&self) -> StatusCode {
+        StatusCode::FORBIDDEN
+    }
+}
+
+impl Status for RevokeApiKeyError {
+    fn status(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+}
+
+impl Status for SetProjectMemberError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl Status for RemoveProjectMemberError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl Status for SetUserAdminError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl Status for SetUserDisabledError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl Status for UserActivityError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
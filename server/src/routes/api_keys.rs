@@ -0,0 +1,24 @@
+use axum::{Json, extract::State};
+use clusterizer_common::{
+    errors::Infallible,
+    records::{ApiKey, ApiKeyFilter, Select},
+};
+
+use crate::{
+    auth::{Auth, scope},
+    result::AppResult,
+    state::AppState,
+};
+
+pub async fn api_keys(
+    State(state): State<AppState>,
+    Auth(user_id, ..): Auth<scope::AccountManage>,
+) -> AppResult<Json<Vec<ApiKey>>, Infallible> {
+    Ok(Json(
+        ApiKeyFilter::default()
+            .user_id(vec![user_id])
+            .select()
+            .fetch_all(&state.pool)
+            .await?,
+    ))
+}
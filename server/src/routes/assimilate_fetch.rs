@@ -0,0 +1,67 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use clusterizer_common::{
+    errors::AssimilateFetchError,
+    records::{Project, Select, Task},
+    responses::AssimilateFetchResponse,
+    types::{Id, ProjectRole},
+};
+
+use crate::{
+    auth::{Auth, scope},
+    projects,
+    result::{AppError, AppResult, ResultExt},
+    state::AppState,
+};
+
+pub async fn assimilate_fetch(
+    State(state): State<AppState>,
+    Path(project_id): Path<Id<Project>>,
+    auth: Auth<scope::ProjectAssimilate>,
+) -> AppResult<Json<AssimilateFetchResponse>, AssimilateFetchError> {
+    let project = project_id
+        .select()
+        .fetch_one(&state.pool)
+        .await
+        .map_not_found(AssimilateFetchError::InvalidProject)?;
+
+    if !projects::has_role(&state.pool, project.id, auth.0, ProjectRole::Maintainer).await?
+        || !auth.permits(project.id)
+    {
+        Err(AppError::Specific(AssimilateFetchError::Forbidden))?;
+    }
+
+    let tasks = sqlx::query_as_unchecked!(
+        Task,
+        r#"
+        SELECT
+            *
+        FROM
+            tasks
+        WHERE
+            project_id = $1
+            AND canonical_result_id IS NOT NULL
+            AND assimilated_at IS NULL
+        ORDER BY
+            id
+        LIMIT 1000
+        "#,
+        project.id,
+    )
+    .fetch_all(&state.pool)
+    .await?;
+
+    let canonical_result_ids: Vec<_> = tasks
+        .iter()
+        .filter_map(|task| task.canonical_result_id)
+        .collect();
+
+    let canonical_results = canonical_result_ids.select().fetch_all(&state.pool).await?;
+
+    Ok(Json(AssimilateFetchResponse {
+        tasks,
+        canonical_results,
+    }))
+}
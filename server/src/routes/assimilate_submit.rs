@@ -0,0 +1,68 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use clusterizer_common::{
+    errors::AssimilateSubmitError,
+    records::{Project, Select},
+    requests::AssimilateSubmitRequest,
+    types::{Id, ProjectRole},
+};
+
+use crate::{
+    auth::{Auth, scope},
+    projects,
+    result::{AppError, AppResult, ResultExt},
+    state::AppState,
+};
+
+pub async fn assimilate_submit(
+    State(state): State<AppState>,
+    Path(project_id): Path<Id<Project>>,
+    auth: Auth<scope::ProjectAssimilate>,
+    Json(request): Json<AssimilateSubmitRequest>,
+) -> AppResult<(), AssimilateSubmitError> {
+    let mut tx = state.pool.begin().await?;
+
+    let project = project_id
+        .select()
+        .fetch_one(&mut *tx)
+        .await
+        .map_not_found(AssimilateSubmitError::InvalidProject)?;
+
+    if !projects::has_role(&mut *tx, project.id, auth.0, ProjectRole::Maintainer).await?
+        || !auth.permits(project.id)
+    {
+        Err(AppError::Specific(AssimilateSubmitError::Forbidden))?;
+    }
+
+    let tasks = request.task_ids.select().fetch_all(&mut *tx).await?;
+
+    // Only tasks of this project with a canonical result can be assimilated.
+    if tasks.len() != request.task_ids.len()
+        || tasks
+            .iter()
+            .any(|task| task.project_id != project.id || task.canonical_result_id.is_none())
+    {
+        Err(AppError::Specific(AssimilateSubmitError::InvalidTask))?;
+    }
+
+    sqlx::query_unchecked!(
+        r#"
+        UPDATE
+            tasks
+        SET
+            assimilated_at = now()
+        WHERE
+            id = ANY($1)
+            AND assimilated_at IS NULL
+        "#,
+        request.task_ids,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use axum::{Json, extract::State};
+use clusterizer_common::{
+    errors::CreateApiKeyError,
+    records::{ApiKeyBuilder, Insert},
+    requests::CreateApiKeyRequest,
+    responses::CreateApiKeyResponse,
+    types::Scope,
+};
+
+use crate::{
+    auth::{self, Auth, scope},
+    result::{AppError, AppResult},
+    state::AppState,
+};
+
+pub async fn create_api_key(
+    State(state): State<AppState>,
+    auth: Auth<scope::AccountManage>,
+    Json(request): Json<CreateApiKeyRequest>,
+) -> AppResult<Json<CreateApiKeyResponse>, CreateApiKeyError> {
+    let user_id = auth.0;
+
+    let scopes = scopes(auth.1, request.scopes).map_err(AppError::Specific)?;
+
+    let mut tx = state.pool.begin().await?;
+
+    let api_key_id = ApiKeyBuilder { user_id, scopes }
+        .insert()
+        .fetch_one(&mut *tx)
+        .await?;
+
+    if request.revoke_others {
+        sqlx::query_unchecked!(
+            r#"
+            UPDATE
+                api_keys
+            SET
+                revoked_at = now()
+            WHERE
+                user_id = $1
+                AND id != $2
+                AND revoked_at IS NULL
+            "#,
+            user_id,
+            api_key_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(Json(CreateApiKeyResponse {
+        api_key_id,
+        api_key: auth::api_key(&state, api_key_id),
+    }))
+}
+
+/// The scopes of the new key. A restricted key can only hand out the scopes it has itself.
+fn scopes(
+    granted: Option<Vec<Scope>>,
+    requested: Option<Vec<Scope>>,
+) -> Result<Option<Vec<Scope>>, CreateApiKeyError> {
+    match (granted, requested) {
+        (Some(granted), Some(requested)) => {
+            if !requested.iter().all(|scope| granted.contains(scope)) {
+                Err(CreateApiKeyError::ScopeNotGranted)?;
+            }
+
+            Ok(Some(requested))
+        }
+        (Some(granted), None) => Ok(Some(granted)),
+        (None, requested) => Ok(requested),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clusterizer_common::types::Id;
+
+    use super::*;
+
+    #[test]
+    fn unrestricted_keys_can_hand_out_any_scopes() {
+        assert_eq!(scopes(None, None).unwrap(), None);
+        assert_eq!(
+            scopes(None, Some(vec![Scope::UsersManage])).unwrap(),
+            Some(vec![Scope::UsersManage])
+        );
+    }
+
+    #[test]
+    fn restricted_keys_default_to_their_own_scopes() {
+        assert_eq!(
+            scopes(Some(vec![Scope::TasksFetch]), None).unwrap(),
+            Some(vec![Scope::TasksFetch])
+        );
+    }
+
+    #[test]
+    fn restricted_keys_can_hand_out_their_own_scopes() {
+        let granted = vec![
+            Scope::TasksFetch,
+            Scope::ResultsSubmit,
+            Scope::AccountManage,
+        ];
+
+        assert_eq!(
+            scopes(Some(granted.clone()), Some(vec![Scope::TasksFetch])).unwrap(),
+            Some(vec![Scope::TasksFetch])
+        );
+        assert_eq!(scopes(Some(granted), Some(vec![])).unwrap(), Some(vec![]));
+    }
+
+    #[test]
+    fn restricted_keys_cannot_escalate() {
+        let granted = vec![Scope::AccountManage, Scope::ProjectManage(Id::from(1))];
+
+        for requested in [
+            vec![Scope::UsersManage],
+            vec![Scope::AccountManage, Scope::TasksFetch],
+            vec![Scope::ProjectManage(Id::from(2))],
+            vec![Scope::ProjectValidate(Id::from(1))],
+        ] {
+            assert!(matches!(
+                scopes(Some(granted.clone()), Some(requested)),
+                Err(CreateApiKeyError::ScopeNotGranted)
+            ));
+        }
+    }
+}
@@ -8,14 +8,14 @@ use clusterizer_common::{
 use url::Url;
 
 use crate::{
-    auth::Auth,
+    auth::{Auth, scope},
     result::{AppError, AppResult},
     state::AppState,
 };
 
 pub async fn create_file(
     State(state): State<AppState>,
-    Auth(user_id): Auth,
+    Auth(user_id, ..): Auth<scope::FilesCreate>,
     Json(request): Json<CreateFileRequest>,
 ) -> AppResult<Json<Id<File>>, CreateFileError> {
     let user = user_id.select().fetch_one(&state.pool).await?;
@@ -0,0 +1,61 @@
+use axum::{Json, extract::State};
+use clusterizer_common::{
+    errors::CreateTeamError,
+    records::{Insert, Team, TeamBuilder, user::UpdateUser},
+    requests::CreateTeamRequest,
+    types::Id,
+};
+
+use crate::{
+    auth::{Auth, scope},
+    result::{AppError, AppResult, ResultExt},
+    state::AppState,
+    teams,
+};
+
+pub async fn create_team(
+    State(state): State<AppState>,
+    Auth(user_id, ..): Auth<scope::TeamsManage>,
+    Json(request): Json<CreateTeamRequest>,
+) -> AppResult<Json<Id<Team>>, CreateTeamError> {
+    if request.name.len() < 3 {
+        Err(AppError::Specific(CreateTeamError::TooShort))?;
+    }
+
+    if request.name.len() > 32 {
+        Err(AppError::Specific(CreateTeamError::TooLong))?;
+    }
+
+    if !request
+        .name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == ' ')
+    {
+        Err(AppError::Specific(CreateTeamError::InvalidCharacter))?;
+    }
+
+    let mut tx = state.pool.begin().await?;
+
+    if teams::is_founder_with_members(&mut tx, user_id).await? {
+        Err(AppError::Specific(CreateTeamError::FounderOfTeam))?;
+    }
+
+    let team_id = TeamBuilder {
+        founder_user_id: user_id,
+        name: request.name,
+    }
+    .insert()
+    .fetch_one(&mut *tx)
+    .await
+    .map_unique_violation(CreateTeamError::AlreadyExists)?;
+
+    // The founder is the first member of the team.
+    user_id
+        .update_team_id(Some(team_id))
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(Json(team_id))
+}
@@ -0,0 +1,60 @@
+use axum::{Json, extract::State};
+use clusterizer_common::{
+    errors::CreateWebhookError,
+    records::{Insert, Select, WebhookBuilder},
+    requests::CreateWebhookRequest,
+    responses::CreateWebhookResponse,
+    types::ProjectRole,
+};
+use url::Url;
+
+use crate::{
+    auth::{self, Auth, scope},
+    projects,
+    result::{AppError, AppResult, ResultExt},
+    state::AppState,
+};
+
+pub async fn create_webhook(
+    State(state): State<AppState>,
+    auth: Auth<scope::ProjectManage>,
+    Json(request): Json<CreateWebhookRequest>,
+) -> AppResult<Json<CreateWebhookResponse>, CreateWebhookError> {
+    let project = request
+        .project_id
+        .select()
+        .fetch_one(&state.pool)
+        .await
+        .map_not_found(CreateWebhookError::InvalidProject)?;
+
+    if !projects::has_role(&state.pool, project.id, auth.0, ProjectRole::Maintainer).await?
+        || !auth.permits(project.id)
+    {
+        Err(AppError::Specific(CreateWebhookError::Forbidden))?;
+    }
+
+    let url =
+        Url::parse(&request.url).map_err(|_| AppError::Specific(CreateWebhookError::InvalidUrl))?;
+
+    if url.scheme() != "http" && url.scheme() != "https" {
+        Err(AppError::Specific(CreateWebhookError::InvalidUrl))?;
+    }
+
+    if request.events.is_empty() {
+        Err(AppError::Specific(CreateWebhookError::NoEvents))?;
+    }
+
+    let webhook_id = WebhookBuilder {
+        project_id: project.id,
+        url: request.url,
+        events: request.events,
+    }
+    .insert()
+    .fetch_one(&state.pool)
+    .await?;
+
+    Ok(Json(CreateWebhookResponse {
+        webhook_id,
+        secret: auth::webhook_secret(&state, webhook_id),
+    }))
+}
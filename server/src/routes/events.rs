@@ -0,0 +1,55 @@
+use std::convert::Infallible;
+
+use axum::{
+    extract::State,
+    response::{
+        Sse,
+        sse::{self, KeepAlive},
+    },
+};
+use clusterizer_common::requests::EventsRequest;
+use futures_util::{Stream, stream};
+use serde_qs::web::QsQuery;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::{
+    auth::{Auth, scope},
+    state::AppState,
+};
+
+pub async fn events(
+    State(state): State<AppState>,
+    _: Auth<scope::EventsRead>,
+    QsQuery(request): QsQuery<EventsRequest>,
+) -> Sse<impl Stream<Item = Result<sse::Event, Infallible>>> {
+    let receiver = state.events.subscribe();
+
+    let stream = stream::unfold((receiver, request), |(mut receiver, request)| async move {
+        loop {
+            let event = match receiver.recv().await {
+                Ok(event) => event,
+                // Slow clients miss events rather than holding up everyone else.
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return None,
+            };
+
+            if request
+                .project_ids
+                .as_ref()
+                .is_some_and(|project_ids| !project_ids.contains(&event.project_id))
+                || request
+                    .event_types
+                    .as_ref()
+                    .is_some_and(|event_types| !event_types.contains(&event.event_type))
+            {
+                continue;
+            }
+
+            let event = sse::Event::default().json_data(&event).unwrap();
+
+            return Some((Ok(event), (receiver, request)));
+        }
+    });
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
@@ -1,67 +1,866 @@
 use axum::{Json, extract::State};
 use clusterizer_common::{
     errors::FetchTasksError,
-    records::{AssignmentBuilder, Insert, Select, Task},
+    records::{
+        AssignmentBuilder, Host, Insert, Platform, Project, ProjectVersion, Select, Task,
+        task::UpdateTask,
+    },
     requests::FetchTasksRequest,
+    responses::{FetchTasksMetadata, FetchTasksResponse},
+    types::Id,
 };
+use clusterizer_util::Hex;
+use metrics::counter;
+use sha2::{Digest, Sha256};
+
+use std::collections::HashMap;
 
 use crate::{
-    auth::Auth,
+    auth::{Auth, scope},
+    projects,
     result::{AppError, AppResult},
     state::AppState,
 };
 
 pub async fn fetch_tasks(
     State(state): State<AppState>,
-    Auth(user_id): Auth,
+    Auth(user_id, ..): Auth<scope::TasksFetch>,
     Json(request): Json<FetchTasksRequest>,
-) -> AppResult<Json<Vec<Task>>, FetchTasksError> {
+) -> AppResult<Json<FetchTasksResponse>, FetchTasksError> {
+    // Hosts are told apart by their name, so hosts without one would be merged into one.
+    if request.host.name.trim().is_empty() {
+        Err(AppError::Specific(FetchTasksError::InvalidHost))?;
+    }
+
     let mut tx = state.pool.begin().await?;
 
+    let host = sqlx::query_as_unchecked!(
+        Host,
+        r#"
+        INSERT INTO hosts (
+            user_id,
+            name,
+            cpu_model,
+            core_count,
+            memory,
+            os,
+            platform_ids
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (user_id, name) DO UPDATE SET
+            updated_at = now(),
+            cpu_model = EXCLUDED.cpu_model,
+            core_count = EXCLUDED.core_count,
+            memory = EXCLUDED.memory,
+            os = EXCLUDED.os,
+            platform_ids = EXCLUDED.platform_ids
+        RETURNING
+            *
+        "#,
+        user_id,
+        request.host.name,
+        request.host.cpu_model,
+        request.host.core_count,
+        request.host.memory,
+        request.host.os,
+        request.host.platform_ids,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
     let projects = request.project_ids.select().fetch_all(&mut *tx).await?;
 
     if projects.len() != request.project_ids.len() {
         Err(AppError::Specific(FetchTasksError::InvalidProject))?;
     }
 
-    let project_ids: Vec<_> = projects
+    let mut projects_by_project_id: HashMap<_, _> = projects
         .into_iter()
         .filter(|project| project.disabled_at.is_none())
-        .map(|project| project.id)
+        .filter(|project| meets_requirements(&host, project))
+        .map(|project| (project.id, project))
         .collect();
 
-    let tasks = sqlx::query_as_unchecked!(
-        Task,
+    let project_ids: Vec<_> = projects_by_project_id.keys().copied().collect();
+
+    // Fetches of the same user wait for each other, so together they cannot exceed the limits
+    // below.
+    sqlx::query_unchecked!(
         r#"
         SELECT
-            *
+            1 "one!"
         FROM
-            tasks
+            users
         WHERE
-            project_id = ANY($1)
-            AND cardinality(assignment_user_ids) < assignments_needed
-            AND $2 != ALL(assignment_user_ids)
-        FOR UPDATE SKIP LOCKED
-        LIMIT $3
+            id = $1
+        FOR UPDATE
+        "#,
+        user_id,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let user = user_id.select().fetch_one(&mut *tx).await?;
+
+    let hr_classes_by_platform_id: HashMap<_, _> = host
+        .platform_ids
+        .select()
+        .fetch_all(&mut *tx)
+        .await?
+        .into_iter()
+        .map(|platform| (platform.id, platform.hr_class.unwrap_or(platform.name)))
+        .collect();
+
+    // Select the project versions that the host can run, the best one of each platform class.
+    // Platforms listed first by the host are preferred, then the highest version. Beta versions
+    // are only considered for users who opted in.
+    let project_versions = sqlx::query_as_unchecked!(
+        ProjectVersion,
+        r#"
+        SELECT DISTINCT ON (pv.project_id, coalesce(pl.hr_class, pl.name))
+            pv.*
+        FROM
+            project_versions pv,
+            platforms pl
+        WHERE
+            pv.project_id = ANY($1)
+            AND pv.platform_id = ANY($2)
+            AND pv.disabled_at IS NULL
+            AND (NOT pv.is_beta OR $3)
+            AND pl.id = pv.platform_id
+        ORDER BY
+            pv.project_id,
+            coalesce(pl.hr_class, pl.name),
+            array_position($2, pv.platform_id),
+            pv.version DESC
+        "#,
+        project_ids,
+        host.platform_ids,
+        user.beta_opt_in,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    // The preferred version of each project comes first. Tasks of projects with homogeneous
+    // redundancy can be served the version of any class, other projects only need the preferred
+    // one.
+    let mut project_versions_by_project_id: HashMap<_, Vec<_>> = HashMap::new();
+
+    for project_version in project_versions {
+        project_versions_by_project_id
+            .entry(project_version.project_id)
+            .or_default()
+            .push(project_version);
+    }
+
+    for (project_id, project_versions) in &mut project_versions_by_project_id {
+        project_versions.sort_by_key(|project_version| {
+            (
+                host.platform_ids
+                    .iter()
+                    .position(|&platform_id| platform_id == project_version.platform_id),
+                -project_version.version,
+            )
+        });
+
+        if !projects_by_project_id[project_id].homogeneous_redundancy {
+            project_versions.truncate(1);
+        }
+    }
+
+    let mut project_ids: Vec<_> = project_versions_by_project_id.keys().copied().collect();
+
+    // Fetches for projects with a limit on assignments in progress across all users wait for each
+    // other, so together they cannot exceed it. Projects are locked in order to avoid deadlocks.
+    sqlx::query_unchecked!(
+        r#"
+        SELECT
+            1 "one!"
+        FROM
+            projects
+        WHERE
+            id = ANY($1)
+            AND max_in_progress IS NOT NULL
+        ORDER BY
+            id
+        FOR NO KEY UPDATE
         "#,
         project_ids,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    // Projects can limit how many assignments are in progress, in total and per user, and how many
+    // a user gets per day. The daily quota of a user shrinks after invalid or error results, see
+    // `quotas::update`.
+    let limits = sqlx::query_unchecked!(
+        r#"
+        SELECT
+            p.id "project_id: Id<Project>",
+            p.max_in_progress_per_user - (
+                SELECT
+                    count(*)
+                FROM
+                    assignments a,
+                    tasks t
+                WHERE
+                    a.user_id = $1
+                    AND a.state = 'init'
+                    AND t.id = a.task_id
+                    AND t.project_id = p.id
+            ) "in_progress_capacity",
+            p.max_in_progress - (
+                SELECT
+                    count(*)
+                FROM
+                    assignments a,
+                    tasks t
+                WHERE
+                    a.state = 'init'
+                    AND t.id = a.task_id
+                    AND t.project_id = p.id
+            ) "project_in_progress_capacity",
+            CASE
+                WHEN p.daily_quota IS NOT NULL THEN least(q.daily_quota, p.daily_quota) - (
+                    SELECT
+                        count(*)
+                    FROM
+                        assignments a,
+                        tasks t
+                    WHERE
+                        a.user_id = $1
+                        AND a.created_at > now() - interval '1 day'
+                        AND t.id = a.task_id
+                        AND t.project_id = p.id
+                )
+            END "daily_capacity"
+        FROM
+            projects p
+            LEFT JOIN user_quotas q ON
+                q.user_id = $1
+                AND q.project_id = p.id
+        WHERE
+            p.id = ANY($2)
+        "#,
         user_id,
-        request.limit.min(32) as i64,
+        project_ids,
     )
     .fetch_all(&mut *tx)
     .await?;
 
-    for task in &tasks {
-        AssignmentBuilder {
-            task_id: task.id,
-            user_id,
+    let mut capacities_by_project_id = HashMap::new();
+    let mut in_progress_limit_reached = false;
+    let mut project_in_progress_limit_reached = false;
+
+    for limit in limits {
+        let capacity = capacity(
+            limit.in_progress_capacity,
+            limit.project_in_progress_capacity,
+            limit.daily_capacity,
+        );
+
+        match capacity {
+            Ok(Some(capacity)) => {
+                capacities_by_project_id.insert(limit.project_id, capacity);
+            }
+            Ok(None) => {}
+            Err(err) => {
+                in_progress_limit_reached |= matches!(err, FetchTasksError::InProgressLimitReached);
+                project_in_progress_limit_reached |=
+                    matches!(err, FetchTasksError::ProjectInProgressLimitReached);
+
+                capacities_by_project_id.insert(limit.project_id, 0);
+            }
+        }
+    }
+
+    if !project_ids.is_empty() {
+        project_ids.retain(|project_id| capacities_by_project_id.get(project_id) != Some(&0));
+
+        // Tell the user why they get no tasks if every project is at its limit. Limits the user
+        // can do something about come first.
+        if project_ids.is_empty() {
+            if in_progress_limit_reached {
+                Err(AppError::Specific(FetchTasksError::InProgressLimitReached))?;
+            } else if project_in_progress_limit_reached {
+                Err(AppError::Specific(
+                    FetchTasksError::ProjectInProgressLimitReached,
+                ))?;
+            } else {
+                Err(AppError::Specific(FetchTasksError::DailyQuotaReached))?;
+            }
         }
-        .insert()
-        .fetch_one(&mut *tx)
-        .await?;
     }
 
+    // Usage of each project over the last day, used to split tasks according to project shares.
+    let mut usage_by_project_id: HashMap<_, _> = sqlx::query_unchecked!(
+        r#"
+        SELECT
+            t.project_id "project_id: Id<Project>",
+            count(*) "count!"
+        FROM
+            assignments a,
+            tasks t
+        WHERE
+            a.created_at > now() - interval '1 day'
+            AND t.id = a.task_id
+            AND t.project_id = ANY($1)
+        GROUP BY
+            t.project_id
+        "#,
+        project_ids,
+    )
+    .fetch_all(&mut *tx)
+    .await?
+    .into_iter()
+    .map(|row| (row.project_id, row.count))
+    .collect();
+
+    let shares_by_project_id: HashMap<_, _> = projects_by_project_id
+        .values()
+        .map(|project| (project.id, project.share))
+        .collect();
+
+    let mut remaining = request.limit.min(32);
+    let mut tasks = Vec::new();
+    let mut project_version_ids = Vec::new();
+
+    // Each round splits the remaining tasks over the projects that still have tasks. A project
+    // that returns fewer tasks than it was allotted has run out, and its share is redistributed
+    // in the next round.
+    while remaining > 0 && !project_ids.is_empty() {
+        let allotments = allot(
+            remaining,
+            &project_ids,
+            &shares_by_project_id,
+            &usage_by_project_id,
+        );
+
+        for (project_id, allotment) in allotments {
+            let allotment = capacities_by_project_id
+                .get(&project_id)
+                .map_or(allotment, |&capacity| allotment.min(capacity));
+
+            if allotment == 0 {
+                continue;
+            }
+
+            let project = &projects_by_project_id[&project_id];
+            let project_versions = &project_versions_by_project_id[&project_id];
+
+            // Tasks of projects with homogeneous redundancy are only handed out to hosts that can
+            // run a version of the same platform class as the first assignment.
+            let hr_classes: Option<Vec<_>> = project.homogeneous_redundancy.then(|| {
+                project_versions
+                    .iter()
+                    .map(|project_version| {
+                        hr_classes_by_platform_id[&project_version.platform_id].clone()
+                    })
+                    .collect()
+            });
+
+            // Tasks are only handed out once all of their dependencies have a canonical result, and
+            // never after they have failed.
+            // Tasks with a higher priority come first. Within a priority, retries after inconclusive
+            // validation come first, so those tasks finish promptly.
+            let mut project_tasks = sqlx::query_as_unchecked!(
+                Task,
+                r#"
+                SELECT
+                    t.*
+                FROM
+                    tasks t
+                WHERE
+                    t.project_id = $1
+                    AND t.failed_at IS NULL
+                    AND cardinality(t.assignment_user_ids) < t.assignments_needed
+                    AND $2 != ALL(t.assignment_user_ids)
+                    AND ($4::text[] IS NULL OR t.hr_class IS NULL OR t.hr_class = ANY($4))
+                    AND NOT EXISTS (
+                        SELECT
+                            1
+                        FROM
+                            tasks d
+                        WHERE
+                            d.id = ANY(t.dependency_task_ids)
+                            AND d.canonical_result_id IS NULL
+                    )
+                ORDER BY
+                    t.priority DESC,
+                    t.assignments_needed > t.quorum DESC,
+                    t.id
+                FOR UPDATE OF t SKIP LOCKED
+                LIMIT $3
+                "#,
+                project_id,
+                user_id,
+                allotment as i64,
+                hr_classes,
+            )
+            .fetch_all(&mut *tx)
+            .await?;
+
+            for task in &mut project_tasks {
+                let project_version = served_project_version(
+                    project,
+                    project_versions,
+                    &hr_classes_by_platform_id,
+                    task,
+                );
+
+                if task.hr_class.is_none() && project.homogeneous_redundancy {
+                    let hr_class = hr_classes_by_platform_id[&project_version.platform_id].clone();
+
+                    task.id
+                        .update_hr_class(Some(hr_class.clone()))
+                        .execute(&mut *tx)
+                        .await?;
+
+                    task.hr_class = Some(hr_class);
+                }
+
+                // With adaptive replication, a fresh task handed out to a trusted user needs no
+                // other assignments, except for a random share of tasks that are spot checked.
+                if task.assignment_user_ids.is_empty()
+                    && task.assignments_needed == task.quorum
+                    && projects::is_trusted(
+                        project.adaptive_replication_threshold,
+                        user.consecutive_valid_result_count,
+                    )
+                {
+                    let result = sqlx::query_unchecked!(
+                        r#"
+                        UPDATE
+                            tasks
+                        SET
+                            assignments_needed = 1
+                        WHERE
+                            id = $1
+                            AND random() * 100 >= $2
+                        "#,
+                        task.id,
+                        project.adaptive_replication_spot_check_percent,
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+
+                    if result.rows_affected() > 0 {
+                        task.assignments_needed = 1;
+                    }
+                }
+
+                AssignmentBuilder {
+                    task_id: task.id,
+                    user_id,
+                    host_id: host.id,
+                    project_version_id: project_version.id,
+                }
+                .insert()
+                .fetch_one(&mut *tx)
+                .await?;
+
+                project_version_ids.push(project_version.id);
+            }
+
+            if let Some(capacity) = capacities_by_project_id.get_mut(&project_id) {
+                *capacity -= project_tasks.len();
+            }
+
+            // Projects that ran out of tasks or reached their limit are done.
+            if project_tasks.len() < allotment
+                || capacities_by_project_id.get(&project_id) == Some(&0)
+            {
+                project_ids.retain(|&id| id != project_id);
+            }
+
+            *usage_by_project_id.entry(project_id).or_default() += project_tasks.len() as i64;
+            remaining -= project_tasks.len();
+            tasks.extend(project_tasks);
+        }
+    }
+
+    // Gather the metadata of every project the host can run, rather than only of the projects of
+    // these tasks, so the etag only changes when the metadata does. Sorted by id so the etag is
+    // deterministic.
+    let mut project_versions: Vec<_> = project_versions_by_project_id
+        .into_values()
+        .flatten()
+        .collect();
+    let mut projects: Vec<_> = project_versions
+        .iter()
+        .filter_map(|project_version| projects_by_project_id.remove(&project_version.project_id))
+        .collect();
+
+    projects.sort_by_key(|project| project.id);
+    project_versions.sort_by_key(|project_version| project_version.id);
+
+    let file_ids: Vec<_> = project_versions
+        .iter()
+        .map(|project_version| project_version.file_id)
+        .collect();
+
+    let mut files = file_ids.select().fetch_all(&mut *tx).await?;
+
+    files.sort_by_key(|file| file.id);
+
     tx.commit().await?;
 
-    Ok(Json(tasks))
+    counter!("assignments_created_total").increment(tasks.len() as u64);
+
+    let metadata = FetchTasksMetadata {
+        projects,
+        project_versions,
+        files,
+    };
+
+    // Hashed with SHA-256 over the serialized metadata, so the etag stays the same across server
+    // builds and restarts.
+    let metadata_json = serde_json::to_vec(&metadata).expect("metadata should serialize");
+    let metadata_etag = Hex(&Sha256::digest(metadata_json)).to_string();
+
+    // Leave out the metadata if the client already has it.
+    let metadata = if request.metadata_etag.as_ref() == Some(&metadata_etag) {
+        None
+    } else {
+        Some(metadata)
+    };
+
+    Ok(Json(FetchTasksResponse {
+        tasks,
+        project_version_ids,
+        metadata_etag,
+        metadata,
+    }))
+}
+
+/// The version a task is served, which is of the platform class the task is pinned to, if any.
+/// Otherwise it is the preferred version, which comes first.
+fn served_project_version<'a>(
+    project: &Project,
+    project_versions: &'a [ProjectVersion],
+    hr_classes_by_platform_id: &HashMap<Id<Platform>, String>,
+    task: &Task,
+) -> &'a ProjectVersion {
+    let hr_class = task
+        .hr_class
+        .as_ref()
+        .filter(|_| project.homogeneous_redundancy);
+
+    hr_class
+        .and_then(|hr_class| {
+            project_versions.iter().find(|project_version| {
+                &hr_classes_by_platform_id[&project_version.platform_id] == hr_class
+            })
+        })
+        .unwrap_or(&project_versions[0])
+}
+
+/// Splits `count` tasks over the projects in proportion to their shares, giving each next task to
+/// the project with the least usage relative to its share.
+fn allot(
+    count: usize,
+    project_ids: &[Id<Project>],
+    shares_by_project_id: &HashMap<Id<Project>, i32>,
+    usage_by_project_id: &HashMap<Id<Project>, i64>,
+) -> HashMap<Id<Project>, usize> {
+    let mut allotments: HashMap<_, _> = project_ids.iter().map(|&id| (id, 0)).collect();
+
+    for _ in 0..count {
+        let normalized_usage = |project_id: &Id<Project>| {
+            let usage = usage_by_project_id.get(project_id).copied().unwrap_or(0)
+                + allotments[project_id] as i64;
+
+            usage as f64 / shares_by_project_id[project_id] as f64
+        };
+
+        let project_id = *project_ids
+            .iter()
+            .min_by(|a, b| normalized_usage(a).total_cmp(&normalized_usage(b)))
+            .expect("project ids cannot be empty");
+
+        *allotments.get_mut(&project_id).unwrap() += 1;
+    }
+
+    allotments
+}
+
+/// How many more tasks a project can hand out to the user, given how many more assignments each
+/// of its limits allows, or `None` if it has no limits. Fails with the first limit that was
+/// reached.
+fn capacity(
+    in_progress_capacity: Option<i64>,
+    project_in_progress_capacity: Option<i64>,
+    daily_capacity: Option<i64>,
+) -> std::result::Result<Option<usize>, FetchTasksError> {
+    let limits = [
+        (
+            in_progress_capacity,
+            FetchTasksError::InProgressLimitReached,
+        ),
+        (
+            project_in_progress_capacity,
+            FetchTasksError::ProjectInProgressLimitReached,
+        ),
+        (daily_capacity, FetchTasksError::DailyQuotaReached),
+    ];
+
+    let mut min_capacity = None;
+
+    for (capacity, err) in limits {
+        if let Some(capacity) = capacity {
+            if capacity <= 0 {
+                return Err(err);
+            }
+
+            min_capacity = Some(min_capacity.map_or(capacity, |min: i64| min.min(capacity)));
+        }
+    }
+
+    Ok(min_capacity.map(|capacity| capacity as usize))
+}
+
+fn meets_requirements(host: &Host, project: &Project) -> bool {
+    project
+        .min_core_count
+        .is_none_or(|min_core_count| host.core_count >= min_core_count)
+        && project
+            .min_memory
+            .is_none_or(|min_memory| host.memory >= min_memory)
+        && project
+            .required_platform_id
+            .is_none_or(|platform_id| host.platform_ids.contains(&platform_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use clusterizer_common::types::Interval;
+    use sqlx::types::chrono::Utc;
+
+    use super::*;
+
+    fn host(core_count: i32, memory: i64, platform_ids: &[i64]) -> Host {
+        Host {
+            id: Id::from(1),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            user_id: Id::from(1),
+            name: "host".to_string(),
+            cpu_model: "cpu".to_string(),
+            core_count,
+            memory,
+            os: "os".to_string(),
+            platform_ids: platform_ids.iter().copied().map(Id::from).collect(),
+        }
+    }
+
+    fn project(
+        min_core_count: Option<i32>,
+        min_memory: Option<i64>,
+        required_platform_id: Option<i64>,
+    ) -> Project {
+        Project {
+            id: Id::from(1),
+            created_at: Utc::now(),
+            created_by_user_id: Id::from(1),
+            disabled_at: None,
+            name: "project".to_string(),
+            min_core_count,
+            min_memory,
+            required_platform_id: required_platform_id.map(Id::from),
+            homogeneous_redundancy: false,
+            adaptive_replication_threshold: None,
+            adaptive_replication_spot_check_percent: 0,
+            share: 1,
+            follow_up_project_id: None,
+            follow_up_program: None,
+            max_error_results: None,
+            credit_per_result: 0,
+            credit_per_cpu_hour: 0,
+            max_in_progress_per_user: None,
+            daily_quota: None,
+            max_in_progress: None,
+        }
+    }
+
+    fn project_version(id: i64, platform_id: i64) -> ProjectVersion {
+        ProjectVersion {
+            id: Id::from(id),
+            created_at: Utc::now(),
+            disabled_at: None,
+            project_id: Id::from(1),
+            platform_id: Id::from(platform_id),
+            file_id: Id::from(1),
+            version: 1,
+            is_beta: false,
+        }
+    }
+
+    fn task(hr_class: Option<&str>) -> Task {
+        Task {
+            id: Id::from(1),
+            created_at: Utc::now(),
+            deadline: Interval::default(),
+            project_id: Id::from(1),
+            stdin: String::new(),
+            assignments_needed: 1,
+            assignment_user_ids: Vec::new(),
+            quorum: 1,
+            hr_class: hr_class.map(str::to_string),
+            canonical_result_id: None,
+            canonical_hr_class: None,
+            priority: 0,
+            dependency_task_ids: Vec::new(),
+            follow_up_created_at: None,
+            follow_up_attempt_count: 0,
+            follow_up_attempted_at: None,
+            assimilated_at: None,
+            failed_at: None,
+        }
+    }
+
+    fn served_project_version_id(homogeneous_redundancy: bool, hr_class: Option<&str>) -> i64 {
+        let project = Project {
+            homogeneous_redundancy,
+            ..project(None, None, None)
+        };
+        let project_versions = [project_version(10, 1), project_version(20, 2)];
+        let hr_classes_by_platform_id = [
+            (Id::from(1), "linux".to_string()),
+            (Id::from(2), "windows".to_string()),
+        ]
+        .into();
+
+        served_project_version(
+            &project,
+            &project_versions,
+            &hr_classes_by_platform_id,
+            &task(hr_class),
+        )
+        .id
+        .raw()
+    }
+
+    #[test]
+    fn unpinned_tasks_are_served_the_preferred_version() {
+        assert_eq!(served_project_version_id(true, None), 10);
+        assert_eq!(served_project_version_id(false, None), 10);
+    }
+
+    #[test]
+    fn pinned_tasks_are_served_the_version_of_their_class() {
+        assert_eq!(served_project_version_id(true, Some("linux")), 10);
+        assert_eq!(served_project_version_id(true, Some("windows")), 20);
+    }
+
+    #[test]
+    fn pins_are_ignored_without_homogeneous_redundancy() {
+        assert_eq!(served_project_version_id(false, Some("windows")), 10);
+    }
+
+    #[test]
+    fn capacity_without_limits() {
+        assert_eq!(capacity(None, None, None).unwrap(), None);
+    }
+
+    #[test]
+    fn capacity_is_the_smallest_limit() {
+        assert_eq!(capacity(Some(5), None, None).unwrap(), Some(5));
+        assert_eq!(capacity(Some(5), Some(3), Some(10)).unwrap(), Some(3));
+        assert_eq!(capacity(None, Some(7), Some(2)).unwrap(), Some(2));
+    }
+
+    #[test]
+    fn capacity_fails_with_the_limit_reached() {
+        assert!(matches!(
+            capacity(Some(0), Some(0), Some(0)),
+            Err(FetchTasksError::InProgressLimitReached)
+        ));
+        assert!(matches!(
+            capacity(Some(5), Some(0), Some(0)),
+            Err(FetchTasksError::ProjectInProgressLimitReached)
+        ));
+        assert!(matches!(
+            capacity(None, Some(5), Some(-3)),
+            Err(FetchTasksError::DailyQuotaReached)
+        ));
+    }
+
+    #[test]
+    fn projects_without_requirements_accept_any_host() {
+        assert!(meets_requirements(
+            &host(1, 0, &[]),
+            &project(None, None, None)
+        ));
+    }
+
+    #[test]
+    fn hosts_need_enough_cores_and_memory() {
+        let project = project(Some(4), Some(1 << 30), None);
+
+        assert!(meets_requirements(&host(4, 1 << 30, &[]), &project));
+        assert!(meets_requirements(&host(8, 1 << 32, &[]), &project));
+        assert!(!meets_requirements(&host(2, 1 << 32, &[]), &project));
+        assert!(!meets_requirements(&host(8, 1 << 20, &[]), &project));
+    }
+
+    #[test]
+    fn hosts_need_the_required_platform() {
+        let project = project(None, None, Some(2));
+
+        assert!(meets_requirements(&host(1, 0, &[1, 2]), &project));
+        assert!(!meets_requirements(&host(1, 0, &[1]), &project));
+        assert!(!meets_requirements(&host(1, 0, &[]), &project));
+    }
+
+    fn allot_by_id(count: usize, shares: &[(i64, i32)], usage: &[(i64, i64)]) -> Vec<(i64, usize)> {
+        let project_ids: Vec<_> = shares.iter().map(|&(id, _)| Id::from(id)).collect();
+        let shares_by_project_id = shares
+            .iter()
+            .map(|&(id, share)| (Id::from(id), share))
+            .collect();
+        let usage_by_project_id = usage
+            .iter()
+            .map(|&(id, usage)| (Id::from(id), usage))
+            .collect();
+
+        let mut allotments: Vec<_> = allot(
+            count,
+            &project_ids,
+            &shares_by_project_id,
+            &usage_by_project_id,
+        )
+        .into_iter()
+        .map(|(project_id, allotment)| (project_id.raw(), allotment))
+        .collect();
+
+        allotments.sort();
+        allotments
+    }
+
+    #[test]
+    fn allot_splits_by_share() {
+        assert_eq!(allot_by_id(8, &[(1, 1), (2, 3)], &[]), vec![(1, 2), (2, 6)]);
+    }
+
+    #[test]
+    fn allot_gives_everything_to_a_single_project() {
+        assert_eq!(allot_by_id(5, &[(1, 1)], &[]), vec![(1, 5)]);
+    }
+
+    #[test]
+    fn allot_catches_up_projects_with_less_usage() {
+        assert_eq!(
+            allot_by_id(4, &[(1, 1), (2, 1)], &[(1, 4)]),
+            vec![(1, 0), (2, 4)]
+        );
+        assert_eq!(
+            allot_by_id(6, &[(1, 1), (2, 1)], &[(1, 4)]),
+            vec![(1, 1), (2, 5)]
+        );
+    }
+
+    #[test]
+    fn allot_nothing() {
+        assert_eq!(allot_by_id(0, &[(1, 1), (2, 1)], &[]), vec![(1, 0), (2, 0)]);
+    }
 }
@@ -0,0 +1,51 @@
+use axum::{Json, extract::State};
+use clusterizer_common::{errors::Infallible, responses::GlobalStatsResponse};
+
+use crate::{result::AppResult, state::AppState};
+
+pub async fn global_stats(
+    State(state): State<AppState>,
+) -> AppResult<Json<GlobalStatsResponse>, Infallible> {
+    // Aggregated from the other statistics, which are already small.
+    let global_stats = sqlx::query_as_unchecked!(
+        GlobalStatsResponse,
+        r#"
+        SELECT
+            u.user_count "user_count!",
+            u.active_user_count "active_user_count!",
+            u.active_host_count "active_host_count!",
+            p.project_count "project_count!",
+            p.task_count "task_count!",
+            p.remaining_task_count "remaining_task_count!",
+            p.in_progress_assignment_count "in_progress_assignment_count!",
+            p.total_credit "total_credit!",
+            least(u.refreshed_at, p.refreshed_at) "refreshed_at"
+        FROM
+            (
+                SELECT
+                    count(*) AS user_count,
+                    count(*) FILTER (WHERE active_host_count > 0) AS active_user_count,
+                    coalesce(sum(active_host_count), 0)::int8 AS active_host_count,
+                    min(refreshed_at) AS refreshed_at
+                FROM
+                    user_stats
+            ) u,
+            (
+                SELECT
+                    count(*) AS project_count,
+                    coalesce(sum(task_count), 0)::int8 AS task_count,
+                    coalesce(sum(remaining_task_count), 0)::int8 AS remaining_task_count,
+                    coalesce(sum(in_progress_assignment_count), 0)::int8
+                        AS in_progress_assignment_count,
+                    coalesce(sum(total_credit), 0)::int8 AS total_credit,
+                    min(refreshed_at) AS refreshed_at
+                FROM
+                    project_stats
+            ) p
+        "#,
+    )
+    .fetch_one(&state.pool)
+    .await?;
+
+    Ok(Json(global_stats))
+}
@@ -0,0 +1,6 @@
+use axum::http::StatusCode;
+
+/// Liveness only: the server is up and handling requests. See `readyz` for its dependencies.
+pub async fn healthz() -> StatusCode {
+    StatusCode::OK
+}
@@ -0,0 +1,24 @@
+use axum::{Json, extract::State};
+use clusterizer_common::{
+    errors::Infallible,
+    records::{Host, HostFilter, Select},
+};
+
+use crate::{
+    auth::{Auth, scope},
+    result::AppResult,
+    state::AppState,
+};
+
+pub async fn hosts(
+    State(state): State<AppState>,
+    Auth(user_id, ..): Auth<scope::AccountManage>,
+) -> AppResult<Json<Vec<Host>>, Infallible> {
+    Ok(Json(
+        HostFilter::default()
+            .user_id(vec![user_id])
+            .select()
+            .fetch_all(&state.pool)
+            .await?,
+    ))
+}
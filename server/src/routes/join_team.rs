@@ -0,0 +1,46 @@
+use axum::extract::{Path, State};
+use clusterizer_common::{
+    errors::JoinTeamError,
+    records::{Select, Team, user::UpdateUser},
+    types::Id,
+};
+
+use crate::{
+    auth::{Auth, scope},
+    result::{AppError, AppResult, ResultExt},
+    state::AppState,
+    teams,
+};
+
+pub async fn join_team(
+    State(state): State<AppState>,
+    Path(team_id): Path<Id<Team>>,
+    Auth(user_id, ..): Auth<scope::TeamsManage>,
+) -> AppResult<(), JoinTeamError> {
+    let mut tx = state.pool.begin().await?;
+
+    let team = team_id
+        .select()
+        .fetch_one(&mut *tx)
+        .await
+        .map_not_found(JoinTeamError::InvalidTeam)?;
+
+    let user = user_id.select().fetch_one(&mut *tx).await?;
+
+    if user.team_id == Some(team.id) {
+        return Ok(());
+    }
+
+    if teams::is_founder_with_members(&mut tx, user_id).await? {
+        Err(AppError::Specific(JoinTeamError::FounderOfTeam))?;
+    }
+
+    user_id
+        .update_team_id(Some(team.id))
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
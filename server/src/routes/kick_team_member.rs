@@ -0,0 +1,52 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use clusterizer_common::{
+    errors::KickTeamMemberError,
+    records::{Select, Team, user::UpdateUser},
+    requests::KickTeamMemberRequest,
+    types::Id,
+};
+
+use crate::{
+    auth::{Auth, scope},
+    result::{AppError, AppResult, ResultExt},
+    state::AppState,
+};
+
+pub async fn kick_team_member(
+    State(state): State<AppState>,
+    Path(team_id): Path<Id<Team>>,
+    Auth(user_id, ..): Auth<scope::TeamsManage>,
+    Json(request): Json<KickTeamMemberRequest>,
+) -> AppResult<(), KickTeamMemberError> {
+    let mut tx = state.pool.begin().await?;
+
+    let team = team_id
+        .select()
+        .fetch_one(&mut *tx)
+        .await
+        .map_not_found(KickTeamMemberError::InvalidTeam)?;
+
+    if team.founder_user_id != user_id {
+        Err(AppError::Specific(KickTeamMemberError::Forbidden))?;
+    }
+
+    let member = request
+        .user_id
+        .select()
+        .fetch_one(&mut *tx)
+        .await
+        .map_not_found(KickTeamMemberError::NotMember)?;
+
+    if member.team_id != Some(team.id) || member.id == team.founder_user_id {
+        Err(AppError::Specific(KickTeamMemberError::NotMember))?;
+    }
+
+    member.id.update_team_id(None).execute(&mut *tx).await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use axum::extract::State;
+use clusterizer_common::{
+    errors::LeaveTeamError,
+    records::{Select, user::UpdateUser},
+};
+
+use crate::{
+    auth::{Auth, scope},
+    result::{AppError, AppResult},
+    state::AppState,
+    teams,
+};
+
+pub async fn leave_team(
+    State(state): State<AppState>,
+    Auth(user_id, ..): Auth<scope::TeamsManage>,
+) -> AppResult<(), LeaveTeamError> {
+    let mut tx = state.pool.begin().await?;
+
+    let user = user_id.select().fetch_one(&mut *tx).await?;
+
+    if user.team_id.is_none() {
+        Err(AppError::Specific(LeaveTeamError::NotInTeam))?;
+    }
+
+    if teams::is_founder_with_members(&mut tx, user_id).await? {
+        Err(AppError::Specific(LeaveTeamError::FounderOfTeam))?;
+    }
+
+    user_id.update_team_id(None).execute(&mut *tx).await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
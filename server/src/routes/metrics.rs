@@ -0,0 +1,12 @@
+use axum::{extract::State, http::header, response::IntoResponse};
+
+use crate::{monitoring, state::AppState};
+
+pub async fn metrics(State(state): State<AppState>) -> impl IntoResponse {
+    monitoring::record_pool(&state.pool);
+
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.metrics.render(),
+    )
+}
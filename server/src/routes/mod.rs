@@ -14,17 +14,71 @@ use crate::{
     state::AppState,
 };
 
+pub mod api_keys;
+pub mod assimilate_fetch;
+pub mod assimilate_submit;
+pub mod create_api_key;
 pub mod create_file;
+pub mod create_team;
+pub mod create_webhook;
+pub mod events;
 pub mod fetch_tasks;
+pub mod global_stats;
+pub mod healthz;
+pub mod hosts;
+pub mod join_team;
+pub mod kick_team_member;
+pub mod leave_team;
+pub mod metrics;
+pub mod project_stats;
+pub mod readyz;
 pub mod register;
+pub mod remove_project_member;
+pub mod revoke_api_key;
+pub mod set_beta_opt_in;
+pub mod set_project_member;
+pub mod set_task_priority;
+pub mod set_user_admin;
+pub mod set_user_disabled;
 pub mod submit_result;
+pub mod team_stats;
+pub mod user_activity;
+pub mod user_quotas;
+pub mod user_stats;
 pub mod validate_fetch;
 pub mod validate_submit;
 
+pub use api_keys::api_keys;
+pub use assimilate_fetch::assimilate_fetch;
+pub use assimilate_submit::assimilate_submit;
+pub use create_api_key::create_api_key;
 pub use create_file::create_file;
+pub use create_team::create_team;
+pub use create_webhook::create_webhook;
+pub use events::events;
 pub use fetch_tasks::fetch_tasks;
+pub use global_stats::global_stats;
+pub use healthz::healthz;
+pub use hosts::hosts;
+pub use join_team::join_team;
+pub use kick_team_member::kick_team_member;
+pub use leave_team::leave_team;
+pub use metrics::metrics;
+pub use project_stats::project_stats;
+pub use readyz::readyz;
 pub use register::register;
+pub use remove_project_member::remove_project_member;
+pub use revoke_api_key::revoke_api_key;
+pub use set_beta_opt_in::set_beta_opt_in;
+pub use set_project_member::set_project_member;
+pub use set_task_priority::set_task_priority;
+pub use set_user_admin::set_user_admin;
+pub use set_user_disabled::set_user_disabled;
 pub use submit_result::submit_result;
+pub use team_stats::team_stats;
+pub use user_activity::user_activity;
+pub use user_quotas::user_quotas;
+pub use user_stats::user_stats;
 pub use validate_fetch::validate_fetch;
 pub use validate_submit::validate_submit;
 
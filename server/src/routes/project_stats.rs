@@ -0,0 +1,44 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use clusterizer_common::{
+    errors::NotFound, records::Project, responses::ProjectStatsResponse, types::Id,
+};
+
+use crate::{
+    result::{AppResult, ResultExt},
+    state::AppState,
+};
+
+pub async fn project_stats(
+    State(state): State<AppState>,
+    Path(project_id): Path<Id<Project>>,
+) -> AppResult<Json<ProjectStatsResponse>, NotFound> {
+    let project_stats = sqlx::query_as_unchecked!(
+        ProjectStatsResponse,
+        r#"
+        SELECT
+            project_id "project_id!",
+            name "name!",
+            task_count "task_count!",
+            remaining_task_count "remaining_task_count!",
+            valid_task_count "valid_task_count!",
+            failed_task_count "failed_task_count!",
+            in_progress_assignment_count "in_progress_assignment_count!",
+            active_user_count "active_user_count!",
+            total_credit "total_credit!",
+            refreshed_at "refreshed_at!"
+        FROM
+            project_stats
+        WHERE
+            project_id = $1
+        "#,
+        project_id,
+    )
+    .fetch_one(&state.pool)
+    .await
+    .map_not_found(NotFound)?;
+
+    Ok(Json(project_stats))
+}
@@ -0,0 +1,93 @@
+use std::time::Duration;
+
+use axum::{Json, extract::State, http::StatusCode};
+use clusterizer_common::responses::{ReadinessCheck, ReadyzResponse};
+
+use crate::{health, state::AppState, tasks};
+
+pub async fn readyz(State(state): State<AppState>) -> (StatusCode, Json<ReadyzResponse>) {
+    let mut checks = Vec::new();
+
+    let database = sqlx::query_unchecked!("SELECT 1 \"one!\"")
+        .fetch_one(&state.pool)
+        .await;
+
+    checks.push(ReadinessCheck {
+        name: "database".to_string(),
+        ok: database.is_ok(),
+        critical: true,
+        message: database.err().map(|err| err.to_string()),
+    });
+
+    let schema_status = health::schema_status(&state.pool).await;
+
+    checks.push(ReadinessCheck {
+        name: "migrations".to_string(),
+        ok: schema_status
+            .as_ref()
+            .is_ok_and(|schema_status| schema_status.pending.is_empty()),
+        critical: true,
+        message: match schema_status {
+            Ok(schema_status) if schema_status.pending.is_empty() => None,
+            Ok(schema_status) => Some(format!("pending migrations: {:?}", schema_status.pending)),
+            Err(err) => Some(err.to_string()),
+        },
+    });
+
+    // Expired assignments are only handed out again once this task has run, and follow-up
+    // tasks only exist once it has run, so a stuck task stalls projects.
+    checks.push(heartbeat_check(
+        &state,
+        tasks::update_expired_assignments::NAME,
+        tasks::update_expired_assignments::INTERVAL,
+    ));
+    checks.push(heartbeat_check(
+        &state,
+        tasks::create_follow_up_tasks::NAME,
+        tasks::create_follow_up_tasks::INTERVAL,
+    ));
+
+    // Webhook deliveries pile up while this task is stuck, and stale statistics are shown. Both
+    // are reported, but do not take the server out of rotation. Deliveries wait on receivers
+    // outside of our control, so a few slow ones can delay a run.
+    checks.push(ReadinessCheck {
+        critical: false,
+        ..heartbeat_check(
+            &state,
+            tasks::deliver_webhooks::NAME,
+            tasks::deliver_webhooks::INTERVAL,
+        )
+    });
+    checks.push(ReadinessCheck {
+        critical: false,
+        ..heartbeat_check(
+            &state,
+            tasks::refresh_stats::NAME,
+            tasks::refresh_stats::INTERVAL,
+        )
+    });
+
+    let ready = checks.iter().all(|check| check.ok || !check.critical);
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (status, Json(ReadyzResponse { ready, checks }))
+}
+
+/// Fails if the background task has not completed a run in three of its intervals.
+fn heartbeat_check(state: &AppState, task: &'static str, interval: Duration) -> ReadinessCheck {
+    let elapsed = state.heartbeats.elapsed(task);
+
+    ReadinessCheck {
+        name: task.to_string(),
+        ok: elapsed.is_some_and(|elapsed| elapsed < 3 * interval),
+        critical: true,
+        message: Some(match elapsed {
+            Some(elapsed) => format!("last ran {}s ago", elapsed.as_secs()),
+            None => "never ran".to_string(),
+        }),
+    }
+}
@@ -1,7 +1,7 @@
 use axum::{Json, extract::State};
 use clusterizer_common::{
     errors::RegisterError,
-    records::{Insert, user::UserBuilder},
+    records::{ApiKeyBuilder, Insert, user::UserBuilder},
     requests::RegisterRequest,
     responses::RegisterResponse,
 };
@@ -32,13 +32,25 @@ pub async fn register(
         Err(AppError::Specific(RegisterError::InvalidCharacter))?;
     }
 
+    let mut tx = state.pool.begin().await?;
+
     let user_id = UserBuilder { name: request.name }
         .insert()
-        .fetch_one(&state.pool)
+        .fetch_one(&mut *tx)
         .await
         .map_unique_violation(RegisterError::AlreadyExists)?;
 
+    let api_key_id = ApiKeyBuilder {
+        user_id,
+        scopes: None,
+    }
+    .insert()
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
     Ok(Json(RegisterResponse {
-        api_key: auth::api_key(&state, user_id),
+        api_key: auth::api_key(&state, api_key_id),
     }))
 }
@@ -0,0 +1,65 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use clusterizer_common::{
+    errors::RemoveProjectMemberError,
+    records::{Project, Select},
+    requests::RemoveProjectMemberRequest,
+    types::{Id, ProjectRole},
+};
+
+use crate::{
+    auth::{Auth, scope},
+    projects,
+    result::{AppError, AppResult, ResultExt},
+    state::AppState,
+};
+
+pub async fn remove_project_member(
+    State(state): State<AppState>,
+    Path(project_id): Path<Id<Project>>,
+    auth: Auth<scope::ProjectManage>,
+    Json(request): Json<RemoveProjectMemberRequest>,
+) -> AppResult<(), RemoveProjectMemberError> {
+    let mut tx = state.pool.begin().await?;
+
+    let project = project_id
+        .select()
+        .fetch_one(&mut *tx)
+        .await
+        .map_not_found(RemoveProjectMemberError::InvalidProject)?;
+
+    if !projects::has_role(&mut *tx, project.id, auth.0, ProjectRole::Owner).await?
+        || !auth.permits(project.id)
+    {
+        Err(AppError::Specific(RemoveProjectMemberError::Forbidden))?;
+    }
+
+    if projects::is_last_owner(&mut tx, project.id, request.user_id).await? {
+        Err(AppError::Specific(RemoveProjectMemberError::LastOwner))?;
+    }
+
+    let removed = sqlx::query_unchecked!(
+        r#"
+        DELETE FROM
+            project_members
+        WHERE
+            project_id = $1
+            AND user_id = $2
+        "#,
+        project.id,
+        request.user_id,
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    if removed == 0 {
+        Err(AppError::Specific(RemoveProjectMemberError::NotMember))?;
+    }
+
+    tx.commit().await?;
+
+    Ok(())
+}
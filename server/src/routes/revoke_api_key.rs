@@ -0,0 +1,41 @@
+use axum::extract::{Path, State};
+use clusterizer_common::{
+    errors::RevokeApiKeyError,
+    records::{ApiKey, Select, api_key::UpdateApiKey},
+    types::Id,
+};
+use sqlx::types::chrono::Utc;
+
+use crate::{
+    auth::{Auth, scope},
+    result::{AppError, AppResult, ResultExt},
+    state::AppState,
+};
+
+pub async fn revoke_api_key(
+    State(state): State<AppState>,
+    Path(api_key_id): Path<Id<ApiKey>>,
+    Auth(user_id, ..): Auth<scope::AccountManage>,
+) -> AppResult<(), RevokeApiKeyError> {
+    let api_key = api_key_id
+        .select()
+        .fetch_one(&state.pool)
+        .await
+        .map_not_found(RevokeApiKeyError::InvalidApiKey)?;
+
+    // Other users' keys are indistinguishable from nonexistent ones.
+    if api_key.user_id != user_id {
+        Err(AppError::Specific(RevokeApiKeyError::InvalidApiKey))?;
+    }
+
+    if api_key.revoked_at.is_some() {
+        Err(AppError::Specific(RevokeApiKeyError::AlreadyRevoked))?;
+    }
+
+    api_key_id
+        .update_revoked_at(Some(Utc::now()))
+        .execute(&state.pool)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use axum::{Json, extract::State};
+use clusterizer_common::{
+    errors::Infallible, records::user::UpdateUser, requests::SetBetaOptInRequest,
+};
+
+use crate::{
+    auth::{Auth, scope},
+    result::AppResult,
+    state::AppState,
+};
+
+pub async fn set_beta_opt_in(
+    State(state): State<AppState>,
+    Auth(user_id, ..): Auth<scope::AccountManage>,
+    Json(request): Json<SetBetaOptInRequest>,
+) -> AppResult<(), Infallible> {
+    user_id
+        .update_beta_opt_in(request.beta_opt_in)
+        .execute(&state.pool)
+        .await?;
+
+    Ok(())
+}
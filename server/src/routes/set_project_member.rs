@@ -0,0 +1,76 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use clusterizer_common::{
+    errors::SetProjectMemberError,
+    records::{Project, Select},
+    requests::SetProjectMemberRequest,
+    types::{Id, ProjectRole},
+};
+
+use crate::{
+    auth::{Auth, scope},
+    projects,
+    result::{AppError, AppResult, ResultExt},
+    state::AppState,
+};
+
+pub async fn set_project_member(
+    State(state): State<AppState>,
+    Path(project_id): Path<Id<Project>>,
+    auth: Auth<scope::ProjectManage>,
+    Json(request): Json<SetProjectMemberRequest>,
+) -> AppResult<(), SetProjectMemberError> {
+    let mut tx = state.pool.begin().await?;
+
+    let project = project_id
+        .select()
+        .fetch_one(&mut *tx)
+        .await
+        .map_not_found(SetProjectMemberError::InvalidProject)?;
+
+    if !projects::has_role(&mut *tx, project.id, auth.0, ProjectRole::Owner).await?
+        || !auth.permits(project.id)
+    {
+        Err(AppError::Specific(SetProjectMemberError::Forbidden))?;
+    }
+
+    request
+        .user_id
+        .select()
+        .fetch_one(&mut *tx)
+        .await
+        .map_not_found(SetProjectMemberError::InvalidUser)?;
+
+    if request.role != ProjectRole::Owner
+        && projects::is_last_owner(&mut tx, project.id, request.user_id).await?
+    {
+        Err(AppError::Specific(SetProjectMemberError::LastOwner))?;
+    }
+
+    sqlx::query_unchecked!(
+        r#"
+        INSERT INTO project_members (
+            project_id,
+            user_id,
+            role
+        ) VALUES (
+            $1,
+            $2,
+            $3
+        )
+        ON CONFLICT (project_id, user_id) DO UPDATE SET
+            role = EXCLUDED.role
+        "#,
+        project.id,
+        request.user_id,
+        request.role,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use clusterizer_common::{
+    errors::SetTaskPriorityError,
+    records::{Project, Select},
+    requests::SetTaskPriorityRequest,
+    types::{Id, ProjectRole},
+};
+
+use crate::{
+    auth::{Auth, scope},
+    projects,
+    result::{AppError, AppResult, ResultExt},
+    state::AppState,
+};
+
+pub async fn set_task_priority(
+    State(state): State<AppState>,
+    Path(project_id): Path<Id<Project>>,
+    auth: Auth<scope::ProjectManage>,
+    Json(request): Json<SetTaskPriorityRequest>,
+) -> AppResult<Json<u64>, SetTaskPriorityError> {
+    let mut tx = state.pool.begin().await?;
+
+    let project = project_id
+        .select()
+        .fetch_one(&mut *tx)
+        .await
+        .map_not_found(SetTaskPriorityError::InvalidProject)?;
+
+    if !projects::has_role(&mut *tx, project.id, auth.0, ProjectRole::Maintainer).await?
+        || !auth.permits(project.id)
+    {
+        Err(AppError::Specific(SetTaskPriorityError::Forbidden))?;
+    }
+
+    // Only tasks of this project can be reprioritized, whatever the filter says.
+    let count = request
+        .filter
+        .project_id(vec![project.id])
+        .update_priority(request.priority)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+    tx.commit().await?;
+
+    Ok(Json(count))
+}
@@ -0,0 +1,51 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use clusterizer_common::{
+    errors::SetUserAdminError,
+    records::{Select, User, user::UpdateUser},
+    requests::SetUserAdminRequest,
+    types::Id,
+};
+
+use crate::{
+    auth::{Auth, scope},
+    result::{AppError, AppResult, ResultExt},
+    state::AppState,
+    users,
+};
+
+pub async fn set_user_admin(
+    State(state): State<AppState>,
+    Path(user_id): Path<Id<User>>,
+    Auth(admin_user_id, ..): Auth<scope::UsersManage>,
+    Json(request): Json<SetUserAdminRequest>,
+) -> AppResult<(), SetUserAdminError> {
+    let admin_user = admin_user_id.select().fetch_one(&state.pool).await?;
+
+    if !admin_user.is_admin {
+        Err(AppError::Specific(SetUserAdminError::Forbidden))?;
+    }
+
+    let mut tx = state.pool.begin().await?;
+
+    user_id
+        .select()
+        .fetch_one(&mut *tx)
+        .await
+        .map_not_found(SetUserAdminError::InvalidUser)?;
+
+    if !request.is_admin && users::is_last_admin(&mut tx, user_id).await? {
+        Err(AppError::Specific(SetUserAdminError::LastAdmin))?;
+    }
+
+    user_id
+        .update_is_admin(request.is_admin)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
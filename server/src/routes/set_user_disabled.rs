@@ -0,0 +1,59 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use clusterizer_common::{
+    errors::SetUserDisabledError,
+    records::{Select, User, user::UpdateUser},
+    requests::SetUserDisabledRequest,
+    types::Id,
+};
+use sqlx::types::chrono::Utc;
+
+use crate::{
+    auth::{Auth, scope},
+    result::{AppError, AppResult, ResultExt},
+    state::AppState,
+    users,
+};
+
+pub async fn set_user_disabled(
+    State(state): State<AppState>,
+    Path(user_id): Path<Id<User>>,
+    Auth(admin_user_id, ..): Auth<scope::UsersManage>,
+    Json(request): Json<SetUserDisabledRequest>,
+) -> AppResult<(), SetUserDisabledError> {
+    let admin_user = admin_user_id.select().fetch_one(&state.pool).await?;
+
+    if !admin_user.is_admin {
+        Err(AppError::Specific(SetUserDisabledError::Forbidden))?;
+    }
+
+    let mut tx = state.pool.begin().await?;
+
+    let user = user_id
+        .select()
+        .fetch_one(&mut *tx)
+        .await
+        .map_not_found(SetUserDisabledError::InvalidUser)?;
+
+    if request.disabled && users::is_last_admin(&mut tx, user_id).await? {
+        Err(AppError::Specific(SetUserDisabledError::LastAdmin))?;
+    }
+
+    // Keep the original time if the user is already disabled.
+    let disabled_at = if request.disabled {
+        Some(user.disabled_at.unwrap_or_else(Utc::now))
+    } else {
+        None
+    };
+
+    user_id
+        .update_disabled_at(disabled_at)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
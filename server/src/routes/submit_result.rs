@@ -10,7 +10,7 @@ use clusterizer_common::{
 };
 
 use crate::{
-    auth::Auth,
+    auth::{Auth, scope},
     result::{AppError, AppResult, ResultExt},
     state::AppState,
 };
@@ -18,9 +18,16 @@ use crate::{
 pub async fn submit_result(
     State(state): State<AppState>,
     Path(task_id): Path<Id<Task>>,
-    Auth(user_id): Auth,
+    Auth(user_id, ..): Auth<scope::ResultsSubmit>,
     Json(request): Json<SubmitResultRequest>,
 ) -> AppResult<(), SubmitResultError> {
+    if request
+        .cpu_time
+        .is_some_and(|cpu_time| cpu_time.is_negative())
+    {
+        Err(AppError::Specific(SubmitResultError::InvalidCpuTime))?;
+    }
+
     let mut tx = state.pool.begin().await?;
 
     let assignment = sqlx::query_as_unchecked!(
@@ -55,6 +62,8 @@ pub async fn submit_result(
         stdout: request.stdout,
         stderr: request.stderr,
         exit_code: request.exit_code,
+        cpu_time: request.cpu_time,
+        wall_time: request.wall_time,
     }
     .insert()
     .fetch_one(&mut *tx)
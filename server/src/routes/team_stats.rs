@@ -0,0 +1,40 @@
+use axum::{Json, extract::State};
+use clusterizer_common::{
+    errors::Infallible, requests::TeamStatsRequest, responses::TeamStatsResponse,
+};
+use serde_qs::web::QsQuery;
+
+use crate::{result::AppResult, state::AppState};
+
+pub async fn team_stats(
+    State(state): State<AppState>,
+    QsQuery(request): QsQuery<TeamStatsRequest>,
+) -> AppResult<Json<Vec<TeamStatsResponse>>, Infallible> {
+    let team_stats = sqlx::query_as_unchecked!(
+        TeamStatsResponse,
+        r#"
+        SELECT
+            team_id "team_id!",
+            name "name!",
+            member_count "member_count!",
+            total_credit "total_credit!",
+            recent_credit "recent_credit!",
+            valid_result_count "valid_result_count!",
+            rank "rank!",
+            refreshed_at "refreshed_at!"
+        FROM
+            team_stats
+        ORDER BY
+            rank,
+            team_id
+        OFFSET $1
+        LIMIT $2
+        "#,
+        request.offset.unwrap_or(0).max(0),
+        request.limit.unwrap_or(100).clamp(0, 1000),
+    )
+    .fetch_all(&state.pool)
+    .await?;
+
+    Ok(Json(team_stats))
+}
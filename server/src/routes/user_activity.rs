@@ -0,0 +1,79 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use clusterizer_common::{
+    errors::UserActivityError,
+    records::{Assignment, Result, Select, User},
+    responses::UserActivityResponse,
+    types::Id,
+};
+
+use crate::{
+    auth::{Auth, scope},
+    result::{AppError, AppResult, ResultExt},
+    state::AppState,
+};
+
+pub async fn user_activity(
+    State(state): State<AppState>,
+    Path(user_id): Path<Id<User>>,
+    Auth(admin_user_id, ..): Auth<scope::UsersManage>,
+) -> AppResult<Json<UserActivityResponse>, UserActivityError> {
+    let admin_user = admin_user_id.select().fetch_one(&state.pool).await?;
+
+    if !admin_user.is_admin {
+        Err(AppError::Specific(UserActivityError::Forbidden))?;
+    }
+
+    user_id
+        .select()
+        .fetch_one(&state.pool)
+        .await
+        .map_not_found(UserActivityError::InvalidUser)?;
+
+    let assignments = sqlx::query_as_unchecked!(
+        Assignment,
+        r#"
+        SELECT
+            *
+        FROM
+            assignments
+        WHERE
+            user_id = $1
+        ORDER BY
+            id DESC
+        LIMIT
+            100
+        "#,
+        user_id,
+    )
+    .fetch_all(&state.pool)
+    .await?;
+
+    let results = sqlx::query_as_unchecked!(
+        Result,
+        r#"
+        SELECT
+            r.*
+        FROM
+            results r
+            JOIN assignments a ON
+                a.id = r.assignment_id
+        WHERE
+            a.user_id = $1
+        ORDER BY
+            r.id DESC
+        LIMIT
+            100
+        "#,
+        user_id,
+    )
+    .fetch_all(&state.pool)
+    .await?;
+
+    Ok(Json(UserActivityResponse {
+        assignments,
+        results,
+    }))
+}
@@ -0,0 +1,24 @@
+use axum::{Json, extract::State};
+use clusterizer_common::{
+    errors::Infallible,
+    records::{Select, UserQuota, UserQuotaFilter},
+};
+
+use crate::{
+    auth::{Auth, scope},
+    result::AppResult,
+    state::AppState,
+};
+
+pub async fn user_quotas(
+    State(state): State<AppState>,
+    Auth(user_id, ..): Auth<scope::AccountManage>,
+) -> AppResult<Json<Vec<UserQuota>>, Infallible> {
+    Ok(Json(
+        UserQuotaFilter::default()
+            .user_id(vec![user_id])
+            .select()
+            .fetch_all(&state.pool)
+            .await?,
+    ))
+}
@@ -0,0 +1,41 @@
+use axum::{Json, extract::State};
+use clusterizer_common::{
+    errors::Infallible, requests::UserStatsRequest, responses::UserStatsResponse,
+};
+use serde_qs::web::QsQuery;
+
+use crate::{result::AppResult, state::AppState};
+
+pub async fn user_stats(
+    State(state): State<AppState>,
+    QsQuery(request): QsQuery<UserStatsRequest>,
+) -> AppResult<Json<Vec<UserStatsResponse>>, Infallible> {
+    let user_stats = sqlx::query_as_unchecked!(
+        UserStatsResponse,
+        r#"
+        SELECT
+            user_id "user_id!",
+            name "name!",
+            total_credit "total_credit!",
+            recent_credit "recent_credit!",
+            valid_result_count "valid_result_count!",
+            invalid_result_count "invalid_result_count!",
+            active_host_count "active_host_count!",
+            rank "rank!",
+            refreshed_at "refreshed_at!"
+        FROM
+            user_stats
+        ORDER BY
+            rank,
+            user_id
+        OFFSET $1
+        LIMIT $2
+        "#,
+        request.offset.unwrap_or(0).max(0),
+        request.limit.unwrap_or(100).clamp(0, 1000),
+    )
+    .fetch_all(&state.pool)
+    .await?;
+
+    Ok(Json(user_stats))
+}
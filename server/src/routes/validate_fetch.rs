@@ -5,11 +5,12 @@ use axum::{
 use clusterizer_common::{
     errors::ValidateFetchError,
     records::{Project, Select, Task},
-    types::Id,
+    types::{Id, ProjectRole},
 };
 
 use crate::{
-    auth::Auth,
+    auth::{Auth, scope},
+    projects,
     result::{AppError, AppResult, ResultExt},
     state::AppState,
 };
@@ -17,7 +18,7 @@ use crate::{
 pub async fn validate_fetch(
     State(state): State<AppState>,
     Path(project_id): Path<Id<Project>>,
-    Auth(user_id): Auth,
+    auth: Auth<scope::ProjectValidate>,
 ) -> AppResult<Json<Vec<Task>>, ValidateFetchError> {
     let project = project_id
         .select()
@@ -25,7 +26,9 @@ pub async fn validate_fetch(
         .await
         .map_not_found(ValidateFetchError::InvalidProject)?;
 
-    if project.created_by_user_id != user_id {
+    if !projects::has_role(&state.pool, project.id, auth.0, ProjectRole::Validator).await?
+        || !auth.permits(project.id)
+    {
         Err(AppError::Specific(ValidateFetchError::Forbidden))?;
     }
 
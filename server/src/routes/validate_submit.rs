@@ -1,22 +1,25 @@
 use axum::{Json, extract::State};
 use clusterizer_common::{
     errors::ValidateSubmitError,
-    records::{Result, Select, Task, result::UpdateResult, task::UpdateTask},
+    records::{Result, Select, Task, User, result::UpdateResult, task::UpdateTask},
     requests::ValidateSubmitRequest,
-    types::{Id, ResultState},
+    types::{Id, ProjectRole, ResultState, WebhookEvent},
 };
+use sqlx::types::chrono::Utc;
 
 use std::collections::HashMap;
 
 use crate::{
-    auth::Auth,
+    auth::{Auth, scope},
+    projects, quotas,
     result::{AppError, AppResult},
     state::AppState,
+    webhooks,
 };
 
 pub async fn validate_submit(
     State(state): State<AppState>,
-    Auth(user_id): Auth,
+    auth: Auth<scope::ProjectValidate>,
     Json(request): Json<ValidateSubmitRequest>,
 ) -> AppResult<(), ValidateSubmitError> {
     // Fetch results from the request.
@@ -74,7 +77,9 @@ pub async fn validate_submit(
     // Check project permissions.
     let project = task.project_id.select().fetch_one(&mut *tx).await?;
 
-    if project.created_by_user_id != user_id {
+    if !projects::has_role(&mut *tx, project.id, auth.0, ProjectRole::Validator).await?
+        || !auth.permits(project.id)
+    {
         Err(AppError::Specific(ValidateSubmitError::Forbidden))?;
     }
 
@@ -142,6 +147,8 @@ pub async fn validate_submit(
         .execute(&mut *tx)
         .await?;
 
+    quotas::update(&mut tx, &project, &error_result_ids).await?;
+
     // Update group ids.
     for (&result_id, &group_id) in &request.results {
         if let Some(group_id) = group_id {
@@ -152,14 +159,39 @@ pub async fn validate_submit(
         }
     }
 
-    // Find the id of a group that meets quorum, if any. When multiple groups meet quorum, we
-    // select the one with the lowest id instead of the largest group. This is needed for
-    // deterministic validation.
-    let valid_group_id = groups
-        .iter()
-        .filter(|(_, results)| results.len() as i32 >= task.quorum)
-        .map(|(&group_id, _)| group_id)
-        .min();
+    // With adaptive replication, a task that was handed out to a single trusted user is accepted
+    // at quorum 1. Trust is checked again here, because it may have been lost since.
+    let quorum = if task.assignments_needed < task.quorum && results.len() == 1 {
+        let user = sqlx::query_as_unchecked!(
+            User,
+            r#"
+            SELECT
+                u.*
+            FROM
+                users u,
+                assignments a
+            WHERE
+                a.id = $1
+                AND u.id = a.user_id
+            "#,
+            results[0].assignment_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if projects::is_trusted(
+            project.adaptive_replication_threshold,
+            user.consecutive_valid_result_count,
+        ) {
+            1
+        } else {
+            task.quorum
+        }
+    } else {
+        task.quorum
+    };
+
+    let valid_group_id = valid_group_id(&groups, quorum);
 
     if let Some(valid_group_id) = valid_group_id {
         // If there was a valid group, update the state of all results.
@@ -182,6 +214,120 @@ pub async fn validate_submit(
         )
         .execute(&mut *tx)
         .await?;
+
+        // Only results that are validated for the first time count towards the validation history,
+        // which are the new results and those left inconclusive by an earlier validation.
+        let changed_result_ids: Vec<_> = results
+            .iter()
+            .filter(|result| group_result_ids.contains(&&result.id))
+            .filter(|result| matches!(result.state, ResultState::Init | ResultState::Inconclusive))
+            .map(|result| result.id)
+            .collect();
+
+        // Update the validation history of the users. An invalid result resets their trust.
+        sqlx::query_unchecked!(
+            r#"
+            UPDATE
+                users u
+            SET
+                valid_result_count = u.valid_result_count + (r.state = 'valid')::int4,
+                invalid_result_count = u.invalid_result_count + (r.state = 'invalid')::int4,
+                consecutive_valid_result_count = CASE
+                    WHEN r.state = 'valid' THEN u.consecutive_valid_result_count + 1
+                    ELSE 0
+                END
+            FROM
+                results r,
+                assignments a
+            WHERE
+                r.id = ANY($1)
+                AND a.id = r.assignment_id
+                AND u.id = a.user_id
+            "#,
+            changed_result_ids,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        quotas::update(&mut tx, &project, &changed_result_ids).await?;
+
+        // Grant credit for the valid results, using the project's credit formula. Results that
+        // were already granted credit in an earlier validation are skipped. The reported cpu time
+        // is clamped to the time the host had the assignment for times its cores, so a client
+        // cannot claim more than it could have used.
+        sqlx::query_unchecked!(
+            r#"
+            INSERT INTO credits (
+                user_id,
+                project_id,
+                result_id,
+                amount
+            )
+            SELECT
+                a.user_id,
+                p.id,
+                r.id,
+                p.credit_per_result
+                    + (p.credit_per_cpu_hour
+                        * extract(epoch FROM greatest(least(
+                            coalesce(r.cpu_time, '0'),
+                            (r.created_at - a.created_at) * coalesce(h.core_count, 1)
+                        ), '0'))
+                        / 3600)::int8
+            FROM
+                results r
+                JOIN assignments a ON
+                    a.id = r.assignment_id
+                LEFT JOIN hosts h ON
+                    h.id = a.host_id,
+                projects p
+            WHERE
+                r.id = ANY($1)
+                AND r.state = 'valid'
+                AND p.id = $2
+            ON CONFLICT (result_id) DO NOTHING
+            "#,
+            group_result_ids,
+            project.id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // The first result of the valid group becomes the canonical result. Record the platform
+        // class it was produced on, so results of homogeneous redundancy projects can be traced.
+        let canonical_hr_class = sqlx::query_scalar_unchecked!(
+            r#"
+            SELECT
+                coalesce(pl.hr_class, pl.name)
+            FROM
+                results r,
+                assignments a,
+                project_versions pv,
+                platforms pl
+            WHERE
+                r.id = $1
+                AND a.id = r.assignment_id
+                AND pv.id = a.project_version_id
+                AND pl.id = pv.platform_id
+            "#,
+            valid_group_id,
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .flatten();
+
+        task.id
+            .update_canonical_result_id(Some(valid_group_id))
+            .execute(&mut *tx)
+            .await?;
+
+        task.id
+            .update_canonical_hr_class(canonical_hr_class)
+            .execute(&mut *tx)
+            .await?;
+
+        webhooks::enqueue(&mut tx, project.id, WebhookEvent::TaskValid, Some(task.id)).await?;
+        webhooks::enqueue_if_drained(&mut tx, project.id).await?;
     } else {
         // Otherwise, update the state of the new results to 'inconclusive'.
         let inconclusive_result_ids: Vec<_> = request
@@ -196,22 +342,108 @@ pub async fn validate_submit(
             .execute(&mut *tx)
             .await?;
 
-        // Finally, update the number of assignments needed.
-        let largest_inconclusive_group = groups
+        // Finally, update the number of assignments needed. There may be no groups at all if
+        // every result errored, which is possible with adaptive replication.
+        let largest_inconclusive_group_len = groups
             .values()
-            .max_by_key(|results| results.len())
-            .expect("there is at least one group");
+            .map(|results| results.len())
+            .max()
+            .unwrap_or(0);
 
         let assignments_needed =
-            (results.len() - largest_inconclusive_group.len()) as i32 + task.quorum;
+            (results.len() - largest_inconclusive_group_len) as i32 + task.quorum;
 
         task.id
             .update_assignments_needed(assignments_needed)
             .execute(&mut *tx)
             .await?;
+
+        // A task fails permanently once it has too many error results.
+        if let Some(max_error_results) = project.max_error_results {
+            let error_result_count = sqlx::query_scalar_unchecked!(
+                r#"
+                SELECT
+                    count(*) "count!"
+                FROM
+                    results r,
+                    assignments a
+                WHERE
+                    a.task_id = $1
+                    AND r.assignment_id = a.id
+                    AND r.state = 'error'
+                "#,
+                task.id,
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+
+            if task.failed_at.is_none() && error_result_count >= max_error_results as i64 {
+                task.id
+                    .update_failed_at(Some(Utc::now()))
+                    .execute(&mut *tx)
+                    .await?;
+
+                webhooks::enqueue(&mut tx, project.id, WebhookEvent::TaskFailed, Some(task.id))
+                    .await?;
+                webhooks::enqueue_if_drained(&mut tx, project.id).await?;
+            }
+        }
     }
 
     tx.commit().await?;
 
     Ok(())
 }
+
+/// Finds the id of a group that meets quorum, if any. When multiple groups meet quorum, we select
+/// the one with the lowest id instead of the largest group. This is needed for deterministic
+/// validation.
+fn valid_group_id(
+    groups: &HashMap<Id<Result>, Vec<Id<Result>>>,
+    quorum: i32,
+) -> Option<Id<Result>> {
+    groups
+        .iter()
+        .filter(|(_, results)| results.len() as i32 >= quorum)
+        .map(|(&group_id, _)| group_id)
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn groups(groups: &[&[i64]]) -> HashMap<Id<Result>, Vec<Id<Result>>> {
+        groups
+            .iter()
+            .map(|result_ids| {
+                let result_ids: Vec<_> = result_ids.iter().copied().map(Id::from).collect();
+
+                (result_ids[0], result_ids)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn single_result_is_valid_at_quorum_one() {
+        let groups = groups(&[&[1]]);
+
+        assert_eq!(valid_group_id(&groups, 1), Some(Id::from(1)));
+        assert_eq!(valid_group_id(&groups, 2), None);
+    }
+
+    #[test]
+    fn valid_group_must_meet_quorum() {
+        let groups = groups(&[&[1], &[2, 3]]);
+
+        assert_eq!(valid_group_id(&groups, 2), Some(Id::from(2)));
+        assert_eq!(valid_group_id(&groups, 3), None);
+    }
+
+    #[test]
+    fn lowest_group_wins() {
+        let groups = groups(&[&[1, 4], &[2, 3, 5]]);
+
+        assert_eq!(valid_group_id(&groups, 2), Some(Id::from(1)));
+    }
+}
@@ -1,7 +1,18 @@
+use std::sync::Arc;
+
+use clusterizer_common::types::Event;
+use metrics_exporter_prometheus::PrometheusHandle;
 use sqlx::PgPool;
+use tokio::sync::broadcast;
+
+use crate::{health::Heartbeats, rate_limit::RateLimiter};
 
 #[derive(Clone)]
 pub struct AppState {
     pub pool: PgPool,
     pub secret: Vec<u8>,
+    pub events: broadcast::Sender<Event>,
+    pub rate_limiter: Arc<RateLimiter>,
+    pub metrics: PrometheusHandle,
+    pub heartbeats: Arc<Heartbeats>,
 }
@@ -0,0 +1,225 @@
+use std::{process::Stdio, time::Duration};
+
+use clusterizer_common::{
+    records::{Insert, Project, Task, TaskBuilder, task::UpdateTask},
+    types::Id,
+};
+use futures_util::{StreamExt, stream};
+use sqlx::types::chrono::Utc;
+use tokio::{io::AsyncWriteExt, process::Command, time};
+use tracing::warn;
+
+use crate::state::AppState;
+
+pub const NAME: &str = "create_follow_up_tasks";
+pub const INTERVAL: Duration = Duration::from_secs(60);
+
+/// How many tasks are followed up per tick, so a backlog is worked off over several ticks.
+const BATCH_SIZE: i64 = 100;
+
+/// How many follow-up programs run at once.
+const CONCURRENCY: usize = 10;
+
+/// How long a follow-up program can run before it is killed. Together with the batch size and
+/// concurrency, this bounds how long a tick takes.
+const PROGRAM_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often a task is attempted before it is given up on.
+const MAX_ATTEMPTS: i32 = 5;
+
+pub async fn create_follow_up_tasks(state: AppState) {
+    let mut interval = time::interval(INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        match create_batch(&state).await {
+            Ok(()) => state.heartbeats.beat(NAME),
+            Err(err) => warn!("Could not create follow-up tasks: {err}."),
+        }
+    }
+}
+
+async fn create_batch(state: &AppState) -> sqlx::Result<()> {
+    // Claim tasks with a canonical result in a project with a follow-up program, which have not
+    // been followed up yet, by counting an attempt. Other ticks and other servers leave claimed
+    // tasks alone for a while, and tasks that failed before come after new ones.
+    let rows = sqlx::query_unchecked!(
+        r#"
+        UPDATE
+            tasks t
+        SET
+            follow_up_attempt_count = t.follow_up_attempt_count + 1,
+            follow_up_attempted_at = now()
+        FROM
+            projects p,
+            results r
+        WHERE
+            t.id IN (
+                SELECT
+                    t.id
+                FROM
+                    tasks t,
+                    projects p
+                WHERE
+                    t.canonical_result_id IS NOT NULL
+                    AND t.follow_up_created_at IS NULL
+                    AND t.follow_up_attempt_count < $2
+                    AND (
+                        t.follow_up_attempted_at IS NULL
+                        OR t.follow_up_attempted_at < now() - interval '10 minutes'
+                    )
+                    AND p.id = t.project_id
+                    AND p.follow_up_project_id IS NOT NULL
+                    AND p.follow_up_program IS NOT NULL
+                ORDER BY
+                    t.follow_up_attempt_count,
+                    t.id
+                FOR UPDATE OF t SKIP LOCKED
+                LIMIT $1
+            )
+            AND p.id = t.project_id
+            AND r.id = t.canonical_result_id
+        RETURNING
+            t.id "task_id: Id<Task>",
+            t.follow_up_attempt_count,
+            p.follow_up_project_id "follow_up_project_id!: Id<Project>",
+            p.follow_up_program "follow_up_program!",
+            r.stdout
+        "#,
+        BATCH_SIZE,
+        MAX_ATTEMPTS,
+    )
+    .fetch_all(&state.pool)
+    .await?;
+
+    // The programs run outside of a transaction, so no locks are held while they run.
+    let outputs: Vec<_> = stream::iter(rows)
+        .map(|row| async move {
+            let stdin = transform(&row.follow_up_program, &row.stdout).await;
+
+            if stdin.is_none() && row.follow_up_attempt_count >= MAX_ATTEMPTS {
+                warn!(
+                    "Giving up on following up task {} after {MAX_ATTEMPTS} attempts.",
+                    row.task_id
+                );
+            }
+
+            (row.task_id, row.follow_up_project_id, stdin)
+        })
+        .buffer_unordered(CONCURRENCY)
+        .collect()
+        .await;
+
+    for (task_id, follow_up_project_id, stdin) in outputs {
+        if let Some(stdin) = stdin {
+            create(state, task_id, follow_up_project_id, &stdin).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates the follow-up tasks of a task, unless another server already did.
+async fn create(
+    state: &AppState,
+    task_id: Id<Task>,
+    follow_up_project_id: Id<Project>,
+    stdin: &str,
+) -> sqlx::Result<()> {
+    let mut tx = state.pool.begin().await?;
+
+    let Some(task) = sqlx::query_as_unchecked!(
+        Task,
+        r#"
+        SELECT
+            *
+        FROM
+            tasks
+        WHERE
+            id = $1
+            AND follow_up_created_at IS NULL
+        FOR UPDATE
+        "#,
+        task_id,
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    else {
+        return Ok(());
+    };
+
+    // Each line of output from the program becomes a new task in the follow-up project, which
+    // depends on the task it was created from.
+    for line in stdin.lines().filter(|line| !line.is_empty()) {
+        TaskBuilder {
+            deadline: task.deadline,
+            project_id: follow_up_project_id,
+            stdin: line.to_string(),
+            quorum: task.quorum,
+            priority: task.priority,
+            dependency_task_ids: vec![task.id],
+        }
+        .insert()
+        .fetch_one(&mut *tx)
+        .await?;
+    }
+
+    task.id
+        .update_follow_up_created_at(Some(Utc::now()))
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await
+}
+
+/// Runs the follow-up program with the canonical stdout as its stdin. Failures are logged and the
+/// task is retried later, until it runs out of attempts.
+async fn transform(program: &str, stdout: &str) -> Option<String> {
+    let mut child = match Command::new(program)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(err) => {
+            warn!("Could not spawn follow-up program {program}: {err}.");
+            return None;
+        }
+    };
+
+    let mut child_stdin = child.stdin.take().expect("stdin is piped");
+    let input = stdout.to_string();
+
+    let write = tokio::spawn(async move { child_stdin.write_all(input.as_bytes()).await });
+
+    // The child is killed when it is dropped on timeout.
+    let Ok(output) = time::timeout(PROGRAM_TIMEOUT, child.wait_with_output()).await else {
+        warn!("Follow-up program {program} timed out after {PROGRAM_TIMEOUT:?}.");
+        return None;
+    };
+
+    if let Ok(Err(err)) = write.await {
+        warn!("Could not write to follow-up program {program}: {err}.");
+        return None;
+    }
+
+    match output {
+        Ok(output) if output.status.success() => match String::from_utf8(output.stdout) {
+            Ok(stdout) => Some(stdout),
+            Err(err) => {
+                warn!("Follow-up program {program} wrote invalid UTF-8: {err}.");
+                None
+            }
+        },
+        Ok(output) => {
+            warn!("Follow-up program {program} exited with {}.", output.status);
+            None
+        }
+        Err(err) => {
+            warn!("Could not run follow-up program {program}: {err}.");
+            None
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::time::Duration;
+
+use clusterizer_common::{
+    records::{Webhook, WebhookDelivery},
+    types::Id,
+};
+use clusterizer_util::Hex;
+use futures_util::{StreamExt, stream};
+use hmac::{Hmac, KeyInit, Mac};
+use sha2::Sha256;
+use tokio::time;
+use tracing::warn;
+
+use crate::{auth, state::AppState};
+
+/// Deliveries are given up on after this many attempts. The delay between attempts doubles each
+/// time, starting at one minute.
+const MAX_ATTEMPTS: i32 = 10;
+
+/// How many deliveries are attempted per tick.
+const BATCH_SIZE: i64 = 100;
+
+/// How many deliveries are sent at once. Together with the client timeout and the batch size,
+/// this bounds how long a tick takes.
+const CONCURRENCY: usize = 10;
+
+pub const NAME: &str = "deliver_webhooks";
+pub const INTERVAL: Duration = Duration::from_secs(10);
+
+struct Delivery {
+    id: Id<WebhookDelivery>,
+    webhook_id: Id<Webhook>,
+    event: String,
+    payload: String,
+    url: String,
+}
+
+pub async fn deliver_webhooks(state: AppState) {
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+    {
+        Ok(client) => client,
+        Err(err) => {
+            warn!("Could not create the webhook client, webhooks are not delivered: {err}.");
+            return;
+        }
+    };
+
+    let mut interval = time::interval(INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        // Deliveries that could not be recorded are attempted again on the next tick.
+        match deliver_batch(&state, &client).await {
+            Ok(()) => state.heartbeats.beat(NAME),
+            Err(err) => warn!("Could not deliver webhooks: {err}."),
+        }
+    }
+}
+
+async fn deliver_batch(state: &AppState, client: &reqwest::Client) -> sqlx::Result<()> {
+    let mut tx = state.pool.begin().await?;
+
+    // The deliveries stay locked until the transaction ends, so other servers skip them rather
+    // than delivering them twice.
+    let deliveries = sqlx::query_as_unchecked!(
+        Delivery,
+        r#"
+        SELECT
+            d.id "id: Id<WebhookDelivery>",
+            d.webhook_id "webhook_id: Id<Webhook>",
+            d.event::text "event!",
+            d.payload,
+            w.url
+        FROM
+            webhook_deliveries d,
+            webhooks w
+        WHERE
+            d.delivered_at IS NULL
+            AND d.attempts < $1
+            AND d.next_attempt_at <= now()
+            AND w.id = d.webhook_id
+            AND w.disabled_at IS NULL
+        ORDER BY
+            d.next_attempt_at
+        FOR UPDATE OF d SKIP LOCKED
+        LIMIT $2
+        "#,
+        MAX_ATTEMPTS,
+        BATCH_SIZE,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let attempts: Vec<_> = stream::iter(deliveries)
+        .map(|delivery| async move {
+            let id = delivery.id;
+
+            (id, deliver(state, client, delivery).await)
+        })
+        .buffer_unordered(CONCURRENCY)
+        .collect()
+        .await;
+
+    for (id, (status, error)) in attempts {
+        if let Some(error) = &error {
+            warn!("Webhook delivery {id} failed: {error}.");
+        }
+
+        sqlx::query_unchecked!(
+            r#"
+            UPDATE
+                webhook_deliveries
+            SET
+                attempts = attempts + 1,
+                next_attempt_at = now() + make_interval(mins => 1 << attempts),
+                delivered_at = CASE WHEN $3::text IS NULL THEN now() END,
+                last_status = $2,
+                last_error = $3
+            WHERE
+                id = $1
+            "#,
+            id,
+            status,
+            error,
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await
+}
+
+/// Sends a delivery to its webhook, returning the status of the response and the error, if any.
+async fn deliver(
+    state: &AppState,
+    client: &reqwest::Client,
+    delivery: Delivery,
+) -> (Option<i32>, Option<String>) {
+    let secret = auth::webhook_secret(state, delivery.webhook_id);
+    let signature = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts keys of any length")
+        .chain_update(delivery.payload.as_bytes())
+        .finalize()
+        .into_bytes();
+
+    let response = client
+        .post(&delivery.url)
+        .header("content-type", "application/json")
+        .header("x-clusterizer-event", &delivery.event)
+        .header("x-clusterizer-delivery", delivery.id.to_string())
+        .header(
+            "x-clusterizer-signature",
+            format!("sha256={}", Hex(&signature)),
+        )
+        .body(delivery.payload)
+        .send()
+        .await;
+
+    match response {
+        Ok(response) if response.status().is_success() => {
+            (Some(response.status().as_u16() as i32), None)
+        }
+        Ok(response) => (
+            Some(response.status().as_u16() as i32),
+            Some(format!("unexpected status {}", response.status())),
+        ),
+        Err(err) => (None, Some(err.to_string())),
+    }
+}
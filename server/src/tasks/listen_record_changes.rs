@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use sqlx::postgres::PgListener;
+use tokio::time;
+use tracing::warn;
+
+use crate::state::AppState;
+
+const MIN_RETRY_DELAY: Duration = Duration::from_secs(1);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Forwards the notifications sent by the record change triggers to the event stream. When the
+/// connection fails, the listener is connected again with exponential backoff up to a minute
+/// between attempts, so a database that is unavailable does not stop the server or flood the
+/// logs. Notifications sent in the meantime are lost.
+pub async fn listen_record_changes(state: AppState) {
+    let mut delay = MIN_RETRY_DELAY;
+
+    loop {
+        match listen(&state).await {
+            Ok(mut listener) => loop {
+                let notification = match listener.recv().await {
+                    Ok(notification) => notification,
+                    Err(err) => {
+                        warn!(
+                            "Could not receive record change, retrying in {}s: {err}.",
+                            delay.as_secs()
+                        );
+                        break;
+                    }
+                };
+
+                delay = MIN_RETRY_DELAY;
+
+                match serde_json::from_str(notification.payload()) {
+                    // Sending only fails if nobody is subscribed, which is fine.
+                    Ok(event) => _ = state.events.send(event),
+                    Err(err) => warn!("Invalid record change payload: {err}."),
+                }
+            },
+            Err(err) => warn!(
+                "Could not listen for record changes, retrying in {}s: {err}.",
+                delay.as_secs()
+            ),
+        }
+
+        time::sleep(delay).await;
+        delay = (delay * 2).min(MAX_RETRY_DELAY);
+    }
+}
+
+async fn listen(state: &AppState) -> sqlx::Result<PgListener> {
+    let mut listener = PgListener::connect_with(&state.pool).await?;
+
+    listener.listen("record_changes").await?;
+
+    Ok(listener)
+}
@@ -1,3 +1,15 @@
+pub mod create_follow_up_tasks;
+pub mod deliver_webhooks;
+pub mod listen_record_changes;
+pub mod prune_rate_limits;
+pub mod refresh_stats;
 pub mod update_expired_assignments;
+pub mod update_metrics;
 
+pub use create_follow_up_tasks::create_follow_up_tasks;
+pub use deliver_webhooks::deliver_webhooks;
+pub use listen_record_changes::listen_record_changes;
+pub use prune_rate_limits::prune_rate_limits;
+pub use refresh_stats::refresh_stats;
 pub use update_expired_assignments::update_expired_assignments;
+pub use update_metrics::update_metrics;
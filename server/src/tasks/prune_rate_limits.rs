@@ -0,0 +1,15 @@
+use std::time::Duration;
+
+use tokio::time;
+
+use crate::state::AppState;
+
+pub async fn prune_rate_limits(state: AppState) {
+    let mut interval = time::interval(Duration::from_secs(60));
+
+    loop {
+        interval.tick().await;
+
+        state.rate_limiter.prune();
+    }
+}
@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use tokio::time;
+use tracing::warn;
+
+use crate::state::AppState;
+
+pub const NAME: &str = "refresh_stats";
+pub const INTERVAL: Duration = Duration::from_secs(300);
+
+pub async fn refresh_stats(state: AppState) {
+    let mut interval = time::interval(INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        // A failed refresh keeps the previous statistics until the next tick.
+        match refresh(&state).await {
+            Ok(()) => state.heartbeats.beat(NAME),
+            Err(err) => warn!("Could not refresh statistics: {err}."),
+        }
+    }
+}
+
+async fn refresh(state: &AppState) -> sqlx::Result<()> {
+    // Refreshing concurrently keeps the statistics readable while they are recomputed.
+    sqlx::query_unchecked!("REFRESH MATERIALIZED VIEW CONCURRENTLY user_stats")
+        .execute(&state.pool)
+        .await?;
+
+    // Team statistics build on the user statistics, so they are refreshed after them.
+    sqlx::query_unchecked!("REFRESH MATERIALIZED VIEW CONCURRENTLY team_stats")
+        .execute(&state.pool)
+        .await?;
+
+    sqlx::query_unchecked!("REFRESH MATERIALIZED VIEW CONCURRENTLY project_stats")
+        .execute(&state.pool)
+        .await?;
+
+    Ok(())
+}
@@ -1,28 +1,69 @@
 use std::time::Duration;
 
+use metrics::counter;
 use tokio::time;
+use tracing::warn;
 
 use crate::state::AppState;
 
+pub const NAME: &str = "update_expired_assignments";
+pub const INTERVAL: Duration = Duration::from_secs(60);
+
 pub async fn update_expired_assignments(state: AppState) {
-    let mut interval = time::interval(Duration::from_secs(60));
+    let mut interval = time::interval(INTERVAL);
 
     loop {
         interval.tick().await;
 
-        sqlx::query_unchecked!(
-            r#"
-            UPDATE
-                assignments
-            SET
-                state = 'expired'
-            WHERE
-                state = 'init'
-                AND deadline_at < now()
-            "#
-        )
-        .execute(&state.pool)
-        .await
-        .unwrap();
+        let result = update(&state).await;
+
+        // A failed run is retried on the next tick. Readiness reports the task as stuck if that
+        // keeps happening.
+        match result {
+            Ok(expired_count) => {
+                counter!("assignments_expired_total").increment(expired_count);
+                state.heartbeats.beat(NAME);
+            }
+            Err(err) => warn!("Could not update expired assignments: {err}."),
+        }
     }
 }
+
+async fn update(state: &AppState) -> sqlx::Result<u64> {
+    let mut tx = state.pool.begin().await?;
+
+    let result = sqlx::query_unchecked!(
+        r#"
+        UPDATE
+            assignments
+        SET
+            state = 'expired'
+        WHERE
+            state = 'init'
+            AND deadline_at < now()
+        "#
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // Tasks with homogeneous redundancy are pinned to the platform class of their first
+    // assignment. Once all of their assignments have expired or were canceled, any host may pick
+    // them up again.
+    sqlx::query_unchecked!(
+        r#"
+        UPDATE
+            tasks
+        SET
+            hr_class = NULL
+        WHERE
+            hr_class IS NOT NULL
+            AND cardinality(assignment_user_ids) = 0
+        "#
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use clusterizer_common::{records::Project, types::Id};
+use metrics::gauge;
+use tokio::time;
+use tracing::warn;
+
+use crate::state::AppState;
+
+pub async fn update_metrics(state: AppState) {
+    let mut interval = time::interval(Duration::from_secs(60));
+
+    loop {
+        interval.tick().await;
+
+        state.metrics.run_upkeep();
+
+        // Tasks that still need assignments, so stalled projects can be alerted on. Projects
+        // without waiting tasks are included, so their gauge drops to zero.
+        let tasks_waiting = sqlx::query_unchecked!(
+            r#"
+            SELECT
+                p.id "project_id: Id<Project>",
+                count(t.id) "count!"
+            FROM
+                projects p
+                LEFT JOIN tasks t ON
+                    t.project_id = p.id
+                    AND t.failed_at IS NULL
+                    AND t.canonical_result_id IS NULL
+                    AND cardinality(t.assignment_user_ids) < t.assignments_needed
+            GROUP BY
+                p.id
+            "#
+        )
+        .fetch_all(&state.pool)
+        .await;
+
+        // The gauges keep their previous values until the next tick.
+        let tasks_waiting = match tasks_waiting {
+            Ok(tasks_waiting) => tasks_waiting,
+            Err(err) => {
+                warn!("Could not count waiting tasks: {err}.");
+                continue;
+            }
+        };
+
+        for row in tasks_waiting {
+            gauge!("tasks_waiting", "project_id" => row.project_id.to_string())
+                .set(row.count as f64);
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use clusterizer_common::{records::User, types::Id};
+use sqlx::PgConnection;
+
+/// Whether the user founded the team they are in, and it has other members. Such a founder cannot
+/// leave their team, so it is not left without anyone to manage it.
+pub async fn is_founder_with_members(
+    conn: &mut PgConnection,
+    user_id: Id<User>,
+) -> sqlx::Result<bool> {
+    sqlx::query_scalar_unchecked!(
+        r#"
+        SELECT EXISTS (
+            SELECT
+                1
+            FROM
+                users u,
+                teams t,
+                users m
+            WHERE
+                u.id = $1
+                AND t.id = u.team_id
+                AND t.founder_user_id = u.id
+                AND m.team_id = t.id
+                AND m.id != u.id
+        ) "exists!"
+        "#,
+        user_id,
+    )
+    .fetch_one(conn)
+    .await
+}
@@ -0,0 +1,48 @@
+use clusterizer_common::{records::User, types::Id};
+use sqlx::PgConnection;
+
+/// Whether the user is the only enabled admin. The admins are locked until the end of the
+/// transaction, so two admins cannot step down at the same time.
+pub async fn is_last_admin(conn: &mut PgConnection, user_id: Id<User>) -> sqlx::Result<bool> {
+    let admin_user_ids = sqlx::query_scalar_unchecked!(
+        r#"
+        SELECT
+            id "id: Id<User>"
+        FROM
+            users
+        WHERE
+            is_admin
+            AND disabled_at IS NULL
+        FOR UPDATE
+        "#,
+    )
+    .fetch_all(conn)
+    .await?;
+
+    Ok(is_last(&admin_user_ids, user_id))
+}
+
+fn is_last(admin_user_ids: &[Id<User>], user_id: Id<User>) -> bool {
+    admin_user_ids == [user_id]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_admin_is_last() {
+        assert!(is_last(&[Id::from(1)], Id::from(1)));
+    }
+
+    #[test]
+    fn one_of_several_admins_is_not_last() {
+        assert!(!is_last(&[Id::from(1), Id::from(2)], Id::from(1)));
+    }
+
+    #[test]
+    fn non_admin_is_not_last() {
+        assert!(!is_last(&[Id::from(1)], Id::from(2)));
+        assert!(!is_last(&[], Id::from(2)));
+    }
+}
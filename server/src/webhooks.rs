@@ -0,0 +1,74 @@
+use clusterizer_common::{
+    records::{Project, Task},
+    types::{Id, WebhookEvent},
+};
+use sqlx::PgConnection;
+
+/// Queues a delivery of `event` to every enabled webhook of the project subscribed to it.
+pub async fn enqueue(
+    conn: &mut PgConnection,
+    project_id: Id<Project>,
+    event: WebhookEvent,
+    task_id: Option<Id<Task>>,
+) -> sqlx::Result<()> {
+    sqlx::query_unchecked!(
+        r#"
+        INSERT INTO webhook_deliveries (
+            webhook_id,
+            event,
+            payload
+        )
+        SELECT
+            id,
+            $2,
+            jsonb_build_object(
+                'event', $2::webhook_event,
+                'project_id', $1::int8,
+                'task_id', $3::int8
+            )::text
+        FROM
+            webhooks
+        WHERE
+            project_id = $1
+            AND disabled_at IS NULL
+            AND $2 = ANY(events)
+        "#,
+        project_id,
+        event,
+        task_id,
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Queues a `QueueDrained` event if every task of the project is now either valid or failed.
+pub async fn enqueue_if_drained(
+    conn: &mut PgConnection,
+    project_id: Id<Project>,
+) -> sqlx::Result<()> {
+    let drained = sqlx::query_scalar_unchecked!(
+        r#"
+        SELECT NOT EXISTS (
+            SELECT
+                1
+            FROM
+                tasks
+            WHERE
+                project_id = $1
+                AND canonical_result_id IS NULL
+                AND failed_at IS NULL
+        ) "drained!"
+        "#,
+        project_id,
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    if drained {
+        enqueue(conn, project_id, WebhookEvent::QueueDrained, None).await?;
+    }
+
+    Ok(())
+}